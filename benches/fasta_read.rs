@@ -0,0 +1,79 @@
+//! Compares `FastaReader` with the zero-copy `RefReader` on a synthetic FASTA file.
+//!
+//! The file is 1 GiB by default; set `RUSTALIND_BENCH_BYTES` to change that.
+//! Run with `cargo bench --bench fasta_read`.
+
+extern crate rustalind;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+use rustalind::io::{FastaReader, RefReader};
+
+const DEFAULT_BYTES: u64 = 1 << 30;
+const RECORD_LENGTH: usize = 10_000;
+const LINE_WIDTH: usize = 60;
+
+fn write_synthetic_fasta(path: &Path, target_bytes: u64) -> std::io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    // A cheap linear congruential generator is plenty for filler sequence.
+    let mut state: u32 = 12345;
+    let mut written = 0u64;
+    let mut record = 0;
+    let mut line = Vec::with_capacity(LINE_WIDTH + 1);
+    while written < target_bytes {
+        let header = format!(">seq{} synthetic record\n", record);
+        out.write_all(header.as_bytes())?;
+        written += header.len() as u64;
+        for chunk_start in (0..RECORD_LENGTH).step_by(LINE_WIDTH) {
+            line.clear();
+            for _ in chunk_start..(chunk_start + LINE_WIDTH).min(RECORD_LENGTH) {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                line.push(b"ACGT"[(state >> 16) as usize & 3]);
+            }
+            line.push(b'\n');
+            out.write_all(&line)?;
+            written += line.len() as u64;
+        }
+        record += 1;
+    }
+    out.flush()
+}
+
+fn report(name: &str, bytes: u64, start: Instant, records: usize, gc: usize) {
+    let seconds = start.elapsed().as_secs_f64();
+    println!("{:<12} {:>8} records {:>12} GC {:>8.3} s {:>8.1} MiB/s",
+             name, records, gc, seconds, bytes as f64 / (1 << 20) as f64 / seconds);
+}
+
+fn main() {
+    let target_bytes = env::var("RUSTALIND_BENCH_BYTES")
+        .ok()
+        .and_then(|bytes| bytes.parse().ok())
+        .unwrap_or(DEFAULT_BYTES);
+
+    let path = env::temp_dir().join("rustalind-fasta-bench.fa");
+    write_synthetic_fasta(&path, target_bytes).expect("Could not write synthetic FASTA");
+    let bytes = fs::metadata(&path).expect("Could not stat synthetic FASTA").len();
+
+    let start = Instant::now();
+    let (mut records, mut gc) = (0, 0);
+    for record in FastaReader::new(File::open(&path).expect("Could not open synthetic FASTA")) {
+        gc += record.expect("Could not read record").gc_count();
+        records += 1;
+    }
+    report("FastaReader", bytes, start, records, gc);
+
+    let start = Instant::now();
+    let (mut records, mut gc) = (0, 0);
+    let mut reader = RefReader::new(File::open(&path).expect("Could not open synthetic FASTA"));
+    while let Some(record) = reader.next() {
+        gc += record.expect("Could not read record").gc_count();
+        records += 1;
+    }
+    report("RefReader", bytes, start, records, gc);
+
+    fs::remove_file(&path).expect("Could not remove synthetic FASTA");
+}
@@ -0,0 +1,190 @@
+use std::cmp;
+use align::Scoring;
+use seq::residue::Residue;
+
+/// Low enough to never win a comparison, with room to subtract penalties.
+pub const NEG_INF: i32 = i32::MIN / 2;
+
+/// What a column of an alignment holds, and so which of Gotoh's three
+/// recurrences a score belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// Two aligned residues.
+    Match,
+    /// A residue of the first sequence against a gap.
+    Up,
+    /// A gap against a residue of the second sequence.
+    Left,
+}
+
+pub const STATES: [State; 3] = [State::Match, State::Up, State::Left];
+
+impl State {
+    fn index(self) -> usize {
+        match self {
+            State::Match => 0,
+            State::Up => 1,
+            State::Left => 2,
+        }
+    }
+
+    /// The gap penalty for a column in this state following one in `previous`:
+    /// a gap run is opened unless `previous` was already the same kind of gap.
+    pub fn penalty(self, previous: State, scoring: &Scoring) -> i32 {
+        let (open, extend) = scoring.gap.open_extend();
+        match self {
+            State::Match => 0,
+            _ if self == previous => extend,
+            _ => open,
+        }
+    }
+}
+
+/// Where a forward pass lets alignments start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Start {
+    /// At the top left corner, after a column in the given state.
+    Corner(State),
+    /// Anywhere along the left column (skipping a prefix of the first
+    /// sequence) or the top row (of the second), as allowed.
+    Borders { left: bool, top: bool },
+    /// At any cell, as in a local alignment.
+    Anywhere,
+}
+
+/// One row of the three dynamic programming tables.
+#[derive(Debug, Clone)]
+pub struct Row {
+    scores: [Vec<i32>; 3],
+}
+
+impl Row {
+    fn new(width: usize) -> Self {
+        Row { scores: [vec![NEG_INF; width], vec![NEG_INF; width], vec![NEG_INF; width]] }
+    }
+
+    pub fn get(&self, state: State, j: usize) -> i32 {
+        self.scores[state.index()][j]
+    }
+
+    fn set(&mut self, state: State, j: usize, score: i32) {
+        self.scores[state.index()][j] = cmp::max(score, NEG_INF);
+    }
+
+    /// Best score at `j` over all states, preferring a match on ties.
+    pub fn best(&self, j: usize) -> (i32, State) {
+        STATES.iter()
+            .map(|&state| (self.get(state, j), state))
+            .fold((NEG_INF, State::Match), |best, next| if next.0 > best.0 { next } else { best })
+    }
+
+    /// Best score of moving into `state` at `j` from a cell of this row.
+    fn enter(&self, state: State, j: usize, scoring: &Scoring) -> i32 {
+        STATES.iter().map(|&previous| self.get(previous, j) - state.penalty(previous, scoring)).max().expect("three states")
+    }
+}
+
+/// Scores of the best paths from wherever `start` allows to each cell.
+/// `visit` sees every row in turn, and the last row is returned.
+pub fn forward<T, F>(a: &[T], b: &[T], scoring: &Scoring, start: Start, mut visit: F) -> Row
+    where T: Residue, F: FnMut(usize, &Row)
+{
+    let width = b.len() + 1;
+    let (prior, free_left, free_top) = match start {
+        Start::Corner(prior) => (prior, false, false),
+        Start::Borders { left, top } => (State::Match, left, top),
+        Start::Anywhere => (State::Match, false, false),
+    };
+    let mut previous = Row::new(width);
+    previous.set(prior, 0, 0);
+    for j in 1..width {
+        let left = previous.enter(State::Left, j - 1, scoring);
+        previous.set(State::Left, j, left);
+        if free_top {
+            previous.set(State::Match, j, 0);
+        }
+    }
+    visit(0, &previous);
+
+    for i in 1..=a.len() {
+        let mut row = Row::new(width);
+        row.set(State::Up, 0, previous.enter(State::Up, 0, scoring));
+        if free_left {
+            row.set(State::Match, 0, 0);
+        }
+        for j in 1..width {
+            let (diagonal, _) = previous.best(j - 1);
+            let diagonal = if start == Start::Anywhere { cmp::max(diagonal, 0) } else { diagonal };
+            row.set(State::Match, j, diagonal + scoring.substitution(a[i - 1], b[j - 1]));
+            row.set(State::Up, j, previous.enter(State::Up, j, scoring));
+            let left = row.enter(State::Left, j - 1, scoring);
+            row.set(State::Left, j, left);
+        }
+        visit(i, &row);
+        previous = row;
+    }
+    previous
+}
+
+/// Scores of the best paths from each cell to the bottom right corner, for
+/// each state the column before that cell could be in. `end`, if given, is
+/// the state the last column must be in. `visit` sees rows from the last
+/// to the first, and the first row is returned.
+pub fn backward<T, F>(a: &[T], b: &[T], scoring: &Scoring, end: Option<State>, mut visit: F) -> Row
+    where T: Residue, F: FnMut(usize, &Row)
+{
+    let (n, m) = (a.len(), b.len());
+    let mut next = Row::new(m + 1);
+    for i in (0..=n).rev() {
+        let mut row = Row::new(m + 1);
+        for j in (0..=m).rev() {
+            for &state in &STATES {
+                let score = if i == n && j == m {
+                    if end.is_none_or(|end| end == state) { 0 } else { NEG_INF }
+                } else {
+                    let matched = if i < n && j < m { next.get(State::Match, j + 1) + scoring.substitution(a[i], b[j]) } else { NEG_INF };
+                    let up = if i < n { next.get(State::Up, j) - State::Up.penalty(state, scoring) } else { NEG_INF };
+                    let left = if j < m { row.get(State::Left, j + 1) - State::Left.penalty(state, scoring) } else { NEG_INF };
+                    cmp::max(matched, cmp::max(up, left))
+                };
+                row.set(state, j, score);
+            }
+        }
+        visit(i, &row);
+        next = row;
+    }
+    next
+}
+
+/// The columns of a best global alignment, found by keeping every row of
+/// the tables, so only for small problems. Gap runs are only closed when
+/// extending them can't explain the score, so they stay together.
+pub fn traceback<T: Residue>(a: &[T], b: &[T], scoring: &Scoring, prior: State, end: Option<State>) -> Vec<State> {
+    let mut rows = Vec::with_capacity(a.len() + 1);
+    forward(a, b, scoring, Start::Corner(prior), |_, row| rows.push(row.clone()));
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut state = match end {
+        Some(end) => end,
+        None => rows[i].best(j).1,
+    };
+
+    let mut columns = Vec::with_capacity(i + j);
+    while i > 0 || j > 0 {
+        let here = rows[i].get(state, j);
+        let (gain, preferred) = match state {
+            State::Match => {
+                i -= 1;
+                j -= 1;
+                (scoring.substitution(a[i], b[j]), [State::Match, State::Up, State::Left])
+            }
+            State::Up => { i -= 1; (0, [State::Up, State::Match, State::Left]) }
+            State::Left => { j -= 1; (0, [State::Left, State::Match, State::Up]) }
+        };
+        columns.push(state);
+        state = *preferred.iter()
+            .find(|&&previous| rows[i].get(previous, j) + gain - state.penalty(previous, scoring) == here)
+            .expect("every score comes from a neighbouring cell");
+    }
+    columns.reverse();
+    columns
+}
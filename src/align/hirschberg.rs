@@ -0,0 +1,217 @@
+use std::ops::Range;
+use align::gotoh::{backward, forward, traceback, Start, State, STATES};
+use align::{Mode, PairwiseAlignment, Scoring};
+use seq::residue::Residue;
+
+/// Problems with at most this many cells are traced back from full tables.
+const BASE_CELLS: usize = 1 << 12;
+
+/// The columns of a best global alignment in linear memory (Hirschberg,
+/// with Myers and Miller's extension to affine gaps). The middle row of the
+/// first sequence is scored from both ends, and the alignment is split
+/// where the path crosses it, along with the state of the column there.
+fn columns<T: Residue>(a: &[T], b: &[T], scoring: &Scoring, prior: State, end: Option<State>, out: &mut Vec<State>) {
+    if a.len() <= 1 || (a.len() + 1) * (b.len() + 1) <= BASE_CELLS {
+        out.extend(traceback(a, b, scoring, prior, end));
+        return;
+    }
+    let middle = a.len() / 2;
+    let to = forward(&a[..middle], b, scoring, Start::Corner(prior), |_, _| ());
+    let from = backward(&a[middle..], b, scoring, end, |_, _| ());
+    let (_, j, state) = (0..=b.len())
+        .flat_map(|j| STATES.iter().map(move |&state| (j, state)))
+        .map(|(j, state)| (to.get(state, j) + from.get(state, j), j, state))
+        .fold(None, |best: Option<(i32, usize, State)>, next| match best {
+            Some(best) if best.0 >= next.0 => Some(best),
+            _ => Some(next),
+        })
+        .expect("the row has cells");
+    columns(&a[..middle], &b[..j], scoring, prior, Some(state), out);
+    columns(&a[middle..], &b[j..], scoring, state, end, out);
+}
+
+/// Lay the residues of `a` and `b` out along the columns, and score them.
+fn build<T: Residue>(a: &[T], b: &[T], scoring: &Scoring, columns: &[State]) -> (i32, (Vec<T>, Vec<T>)) {
+    let mut rows = (Vec::with_capacity(columns.len()), Vec::with_capacity(columns.len()));
+    let (mut i, mut j) = (0, 0);
+    let mut score = 0;
+    let mut previous = State::Match;
+    for &state in columns {
+        score -= state.penalty(previous, scoring);
+        let (x, y) = match state {
+            State::Match => {
+                score += scoring.substitution(a[i], b[j]);
+                i += 1;
+                j += 1;
+                (a[i - 1], b[j - 1])
+            }
+            State::Up => { i += 1; (a[i - 1], T::gap()) }
+            State::Left => { j += 1; (T::gap(), b[j - 1]) }
+        };
+        rows.0.push(x);
+        rows.1.push(y);
+        previous = state;
+    }
+    (score, rows)
+}
+
+/// The parts of each sequence an alignment may leave out, besides local
+/// alignments which may leave out anything.
+#[derive(Debug, Clone, Copy)]
+struct Skippable {
+    a_prefix: bool,
+    b_prefix: bool,
+    a_suffix: bool,
+    b_suffix: bool,
+}
+
+impl Skippable {
+    fn new(mode: Mode) -> Self {
+        let (a_prefix, b_prefix, a_suffix, b_suffix) = match mode {
+            Mode::Global | Mode::Local => (false, false, false, false),
+            Mode::Semiglobal => (true, true, true, true),
+            Mode::Overlap => (true, false, false, true),
+            Mode::Fitting => (true, false, true, false),
+        };
+        Skippable { a_prefix, b_prefix, a_suffix, b_suffix }
+    }
+
+    /// Whether an alignment can start at `(i, j)`: on a border, skipping a
+    /// prefix of at most one sequence.
+    fn start(&self, i: usize, j: usize) -> bool {
+        (i == 0 || self.a_prefix && j == 0) && (j == 0 || self.b_prefix && i == 0)
+    }
+
+    /// Whether an alignment of `n` by `m` residues can end at `(i, j)`.
+    fn end(&self, i: usize, j: usize, n: usize, m: usize) -> bool {
+        (i == n || self.a_suffix && j == m) && (j == m || self.b_suffix && i == n)
+    }
+}
+
+/// The best score in `mode` and the parts of `a` and `b` it aligns. A
+/// forward pass finds where the alignment ends, and a pass back from there
+/// finds where it starts.
+fn region<T: Residue>(a: &[T], b: &[T], scoring: &Scoring, mode: Mode) -> (i32, Range<usize>, Range<usize>) {
+    let (n, m) = (a.len(), b.len());
+    let skippable = Skippable::new(mode);
+    let local = mode == Mode::Local;
+    let start = if local {
+        Start::Anywhere
+    } else {
+        Start::Borders { left: skippable.a_prefix, top: skippable.b_prefix }
+    };
+
+    // An empty local alignment, scoring nothing, is always possible.
+    let mut best = if local { Some((0, 0, 0)) } else { None };
+    forward(a, b, scoring, start, |i, row| {
+        for j in 0..=m {
+            let score = if local { row.get(State::Match, j) } else if skippable.end(i, j, n, m) { row.best(j).0 } else { continue };
+            if best.is_none_or(|(best, _, _)| score > best) {
+                best = Some((score, i, j));
+            }
+        }
+    });
+    let (score, end_i, end_j) = best.expect("the bottom right corner is always an end");
+
+    let mut begin = None;
+    backward(&a[..end_i], &b[..end_j], scoring, None, |i, row| if begin.is_none() {
+        begin = (0..=end_j)
+            .find(|&j| (local || skippable.start(i, j)) && row.get(State::Match, j) == score)
+            .map(|j| (i, j));
+    });
+    let (start_i, start_j) = begin.unwrap_or((end_i, end_j));
+    (score, start_i..end_i, start_j..end_j)
+}
+
+/// Best alignment of `a` against `b` in `mode`, in linear memory. Apart
+/// from a global alignment, the aligned parts are found first and then
+/// aligned globally. Semiglobal alignments get back their free end gaps.
+pub fn align<T: Residue>(a: &[T], b: &[T], scoring: &Scoring, mode: Mode) -> PairwiseAlignment<T> {
+    let (ranges, padded) = match mode {
+        Mode::Global => ((0..a.len(), 0..b.len()), false),
+        _ => {
+            let (_, a_range, b_range) = region(a, b, scoring, mode);
+            ((a_range, b_range), mode == Mode::Semiglobal)
+        }
+    };
+    let (x, y) = (&a[ranges.0.clone()], &b[ranges.1.clone()]);
+    let mut path = Vec::with_capacity(x.len() + y.len());
+    columns(x, y, scoring, State::Match, None, &mut path);
+    let (score, rows) = build(x, y, scoring, &path);
+    if !padded {
+        return PairwiseAlignment { score, rows, ranges };
+    }
+
+    let gaps = |len| vec![T::gap(); len];
+    let after_a = a.len() - ranges.0.end;
+    let after_b = b.len() - ranges.1.end;
+    let first = a[..ranges.0.start].iter().cloned().chain(gaps(ranges.1.start))
+        .chain(rows.0)
+        .chain(a[ranges.0.end..].iter().cloned()).chain(gaps(after_b))
+        .collect();
+    let second = gaps(ranges.0.start).into_iter().chain(b[..ranges.1.start].iter().cloned())
+        .chain(rows.1)
+        .chain(gaps(after_a)).chain(b[ranges.1.end..].iter().cloned())
+        .collect();
+    PairwiseAlignment { score, rows: (first, second), ranges: (0..a.len(), 0..b.len()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use align::SubstitutionMatrix;
+    use seq::translation::TranslatedCodon;
+    use simulate::Rng;
+
+    /// Related protein sequences: `b` is `a` with some substitutions and indels.
+    fn related(rng: &mut Rng, len: usize) -> (Vec<TranslatedCodon>, Vec<TranslatedCodon>) {
+        let letters: Vec<char> = "ACDEFGHIKLMNPQRSTVWY".chars().collect();
+        let residue = |rng: &mut Rng| TranslatedCodon::from_char(letters[rng.below(letters.len())]).unwrap();
+        let a: Vec<_> = (0..len).map(|_| residue(rng)).collect();
+        let mut b = Vec::new();
+        for &r in &a {
+            match rng.below(10) {
+                0 => b.push(residue(rng)),
+                1 => (),
+                2 => b.extend(vec![r, residue(rng), residue(rng)]),
+                _ => b.push(r),
+            }
+        }
+        (a, b)
+    }
+
+    #[test]
+    fn linear_space_matches_full_tables() {
+        let mut rng = Rng::seed_from_u64(7);
+        for scoring in &[Scoring::linear(SubstitutionMatrix::pam250(), 5), Scoring::affine(SubstitutionMatrix::blosum62(), 11, 1)] {
+            for _ in 0..3 {
+                let (a, b) = related(&mut rng, 150);
+                let full = build(&a, &b, scoring, &traceback(&a, &b, scoring, State::Match, None)).0;
+                let alignment = align(&a, &b, scoring, Mode::Global);
+                assert_eq!(alignment.score, full);
+                assert_eq!(alignment.rows.0.iter().filter(|r| !r.is_gap()).count(), a.len());
+                assert_eq!(alignment.rows.1.iter().filter(|r| !r.is_gap()).count(), b.len());
+
+                let padded: Vec<_> = b.iter().chain(&a[..40]).cloned().collect();
+                let mut best = 0;
+                forward(&a[60..], &padded, scoring, Start::Anywhere, |_, row| {
+                    best = (1..=padded.len()).map(|j| row.get(State::Match, j)).fold(best, ::std::cmp::max);
+                });
+                let alignment = align(&a[60..], &padded, scoring, Mode::Local);
+                assert_eq!(alignment.score, best);
+                let (ref first, ref second) = alignment.rows;
+                assert_eq!(first.iter().filter(|r| !r.is_gap()).cloned().collect::<Vec<_>>(), &a[60..][alignment.ranges.0.clone()]);
+                assert_eq!(second.iter().filter(|r| !r.is_gap()).cloned().collect::<Vec<_>>(), &padded[alignment.ranges.1.clone()]);
+
+                // Aligning the parts each mode picks out gets back the score it found.
+                let (prefix, suffix) = (&a[..100], &b[b.len() / 2..]);
+                for &mode in &[Mode::Semiglobal, Mode::Overlap, Mode::Fitting] {
+                    let (score, _, _) = region(&b, prefix, scoring, mode);
+                    assert_eq!(align(&b, prefix, scoring, mode).score, score);
+                    let (score, _, _) = region(suffix, &a, scoring, mode);
+                    assert_eq!(align(suffix, &a, scoring, mode).score, score);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Pairwise sequence alignment over any `Residue` alphabet.
+
+use std::ops::Range;
+use error::Result;
+use seq::residue::Residue;
+
+mod gotoh;
+mod hirschberg;
+mod occurrences;
+pub mod scoring;
+
+pub use self::occurrences::approximate_occurrences;
+pub use self::scoring::{SubstitutionMatrix, MATRIX_NAMES, MAX_SCORE};
+
+/// Which parts of the two sequences an alignment covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Both sequences end to end (Needleman-Wunsch).
+    Global,
+    /// The best scoring pair of substrings (Smith-Waterman).
+    Local,
+    /// Both sequences end to end, without penalising gaps before or after
+    /// either of them.
+    Semiglobal,
+    /// A suffix of the first sequence against a prefix of the second.
+    Overlap,
+    /// The whole of the second sequence against a substring of the first.
+    Fitting,
+}
+
+/// The penalty for a run of gap positions in one sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gap {
+    /// The same cost for every position.
+    Linear(i32),
+    /// `open` for the first position of a run and `extend` for each after it.
+    Affine { open: i32, extend: i32 },
+}
+
+impl Gap {
+    /// Penalties for the first and each later position of a run.
+    pub fn open_extend(self) -> (i32, i32) {
+        match self {
+            Gap::Linear(cost) => (cost, cost),
+            Gap::Affine { open, extend } => (open, extend),
+        }
+    }
+
+    /// Penalty for a run of `len` gap positions.
+    pub fn cost(self, len: usize) -> i32 {
+        let (open, extend) = self.open_extend();
+        match len {
+            0 => 0,
+            _ => open + extend * (len - 1) as i32,
+        }
+    }
+}
+
+/// How alignments are scored: a substitution matrix for aligned residues,
+/// and a penalty for runs of residues aligned against gaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    pub matrix: SubstitutionMatrix,
+    pub gap: Gap,
+}
+
+/// Printable ASCII, for scoring that treats letters as opaque symbols.
+const ANY_LETTER: &str = "!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`{|}~";
+
+impl Scoring {
+    pub fn new(matrix: SubstitutionMatrix, gap: Gap) -> Self {
+        Scoring { matrix, gap }
+    }
+
+    pub fn linear(matrix: SubstitutionMatrix, gap: i32) -> Self {
+        Scoring::new(matrix, Gap::Linear(gap))
+    }
+
+    pub fn affine(matrix: SubstitutionMatrix, open: i32, extend: i32) -> Self {
+        Scoring::new(matrix, Gap::Affine { open, extend })
+    }
+
+    /// Scoring whose best global alignment has score minus the edit
+    /// (Levenshtein) distance: every substitution and gap costs 1.
+    pub fn edit_distance() -> Self {
+        Scoring::linear(SubstitutionMatrix::identity(ANY_LETTER, 0, -1), 1)
+    }
+
+    fn check<T: Residue>(&self, seq: &[T]) -> Result<()> {
+        self.matrix.check(seq)
+    }
+
+    /// Score for aligning `a` with `b`, which `check` has already let through.
+    fn substitution<T: Residue>(&self, a: T, b: T) -> i32 {
+        self.matrix.score(a.to_char(), b.to_char()).expect("residues were checked against the matrix")
+    }
+}
+
+/// Best alignment of `a` against `b` in the given mode, in memory linear in
+/// their lengths. Residues either sequence has that the matrix doesn't
+/// score are a `Domain` error.
+pub fn align<T: Residue>(a: &[T], b: &[T], scoring: &Scoring, mode: Mode) -> Result<PairwiseAlignment<T>> {
+    scoring.check(a)?;
+    scoring.check(b)?;
+    Ok(hirschberg::align(a, b, scoring, mode))
+}
+
+/// Two sequences aligned against each other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairwiseAlignment<T> {
+    pub score: i32,
+    /// The aligned rows, of equal length, with `Residue::gap` in gap columns.
+    pub rows: (Vec<T>, Vec<T>),
+    /// The parts of each sequence that were aligned; all of them for global
+    /// and semiglobal alignments, whose rows hold the whole sequences.
+    pub ranges: (Range<usize>, Range<usize>),
+}
+
+impl<T: Residue> PairwiseAlignment<T> {
+    /// Columns that aren't an identical pair of residues: substitutions and gaps.
+    pub fn edit_distance(&self) -> usize {
+        self.rows.0.iter().zip(&self.rows.1).filter(|&(a, b)| a != b || a.is_gap()).count()
+    }
+
+    /// The aligned rows as text.
+    pub fn row_strings(&self) -> (String, String) {
+        (self.rows.0.iter().map(|r| r.to_char()).collect(), self.rows.1.iter().map(|r| r.to_char()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use problem::parse_sequence;
+    use seq::nuc::DNA;
+    use seq::translation::TranslatedCodon;
+
+    fn protein(s: &str) -> Vec<TranslatedCodon> {
+        parse_sequence(s, TranslatedCodon::from_char).unwrap()
+    }
+
+    #[test]
+    fn edit_distance() {
+        // The EDIT and EDTA samples.
+        let alignment = align(&protein("PRETTY"), &protein("PRTTEIN"), &Scoring::edit_distance(), Mode::Global).unwrap();
+        assert_eq!((alignment.score, alignment.edit_distance()), (-4, 4));
+        let (top, bottom) = alignment.row_strings();
+        assert_eq!(top.len(), bottom.len());
+        assert_eq!(top.replace('-', ""), "PRETTY");
+        assert_eq!(bottom.replace('-', ""), "PRTTEIN");
+        let edit = align(&protein("PLEASANTLY"), &protein("MEANLY"), &Scoring::edit_distance(), Mode::Global).unwrap();
+        assert_eq!(edit.edit_distance(), 5);
+    }
+
+    #[test]
+    fn scored_alignment() {
+        // The GLOB sample.
+        let scoring = Scoring::linear(SubstitutionMatrix::blosum62(), 5);
+        assert_eq!(align(&protein("PLEASANTLY"), &protein("MEANLY"), &scoring, Mode::Global).unwrap().score, 8);
+
+        let dna = |s| parse_sequence(s, DNA::from_iupac).unwrap();
+        let alignment = align(&dna("GATTACA"), &dna("GCATGCT"), &Scoring::linear(SubstitutionMatrix::dna(1, -1), 1), Mode::Global).unwrap();
+        assert_eq!(alignment.score, 0);
+        assert_eq!(alignment.rows.0.len(), alignment.rows.1.len());
+        assert_eq!(align(&dna(""), &dna("AC"), &Scoring::edit_distance(), Mode::Global).unwrap().row_strings(), ("--".to_owned(), "AC".to_owned()));
+        assert!(align(&protein("AX"), &protein("A"), &scoring, Mode::Global).is_ok());
+        let only_a = Scoring::linear(SubstitutionMatrix::parse("A\nA 1").unwrap(), 1);
+        assert!(align(&dna("AC"), &dna("A"), &only_a, Mode::Global).is_err());
+    }
+
+    #[test]
+    fn affine_gaps() {
+        assert_eq!(Gap::Affine { open: 11, extend: 1 }.cost(3), 13);
+        assert_eq!(Gap::Linear(5).cost(3), 15);
+
+        // The GAFF sample, whose three-residue gap must stay in one run.
+        let scoring = Scoring::affine(SubstitutionMatrix::blosum62(), 11, 1);
+        let alignment = align(&protein("PRTEINS"), &protein("PRTWPSEIN"), &scoring, Mode::Global).unwrap();
+        assert_eq!(alignment.score, 8);
+        assert_eq!(alignment.row_strings(), ("PRT---EINS".to_owned(), "PRTWPSEIN-".to_owned()));
+
+        // Linear gaps through the affine engine match plain Needleman-Wunsch.
+        let linear = Scoring::affine(SubstitutionMatrix::blosum62(), 5, 5);
+        assert_eq!(align(&protein("PLEASANTLY"), &protein("MEANLY"), &linear, Mode::Global).unwrap().score, 8);
+    }
+
+    #[test]
+    fn local_alignment() {
+        // The LAFF sample.
+        let scoring = Scoring::affine(SubstitutionMatrix::blosum62(), 11, 1);
+        let alignment = align(&protein("PLEASANTLY"), &protein("MEANLY"), &scoring, Mode::Local).unwrap();
+        assert_eq!(alignment.score, 12);
+        assert_eq!(alignment.row_strings(), ("LEAS".to_owned(), "MEAN".to_owned()));
+        assert_eq!(alignment.ranges, (1..5, 0..4));
+
+        // The LOCA sample.
+        let pam = Scoring::linear(SubstitutionMatrix::pam250(), 5);
+        let alignment = align(&protein("MEANLYPRTEINSTRING"), &protein("PLEASANTLYEINSTEIN"), &pam, Mode::Local).unwrap();
+        assert_eq!(alignment.score, 23);
+        assert_eq!(alignment.ranges, (4..17, 8..18));
+
+        let unrelated = align(&protein("WWW"), &protein("PPP"), &scoring, Mode::Local).unwrap();
+        assert_eq!((unrelated.score, unrelated.rows.0.len()), (0, 0));
+    }
+
+    #[test]
+    fn end_gap_modes() {
+        let dna = |s| parse_sequence(s, DNA::from_iupac).unwrap();
+        let ungapped = |row: &[DNA]| row.iter().filter(|r| !r.is_gap()).cloned().collect::<Vec<_>>();
+
+        // The SMGB sample: rows hold both sequences, with free end gaps.
+        let scoring = Scoring::linear(SubstitutionMatrix::dna(1, -1), 1);
+        let (a, b) = (dna("CAGCACTTGGATTCTCGG"), dna("CAGCGTGG"));
+        let alignment = align(&a, &b, &scoring, Mode::Semiglobal).unwrap();
+        assert_eq!(alignment.score, 4);
+        assert_eq!((ungapped(&alignment.rows.0), ungapped(&alignment.rows.1)), (a.clone(), b.clone()));
+        assert_eq!(alignment.rows.0.len(), alignment.rows.1.len());
+
+        // The OAP sample: a suffix of the first against a prefix of the second.
+        let scoring = Scoring::linear(SubstitutionMatrix::dna(1, -2), 2);
+        let (a, b) = (dna("CTAAGGGATTCCGGTAATTAGACAG"), dna("ATAGACCATATGTCAGTGACTGTGTAA"));
+        let alignment = align(&a, &b, &scoring, Mode::Overlap).unwrap();
+        assert_eq!(alignment.score, 1);
+        assert_eq!((alignment.ranges.0.end, alignment.ranges.1.start), (a.len(), 0));
+
+        // The SIMS sample: the whole of the second against part of the first.
+        let scoring = Scoring::linear(SubstitutionMatrix::dna(1, -1), 1);
+        let text = dna("GCAAACCATAAGCCCTACGTGCCGCCTGTTTAAACTCGCGAACTGAATCTTCTGCTTCACGGTGAAAGTACCACAATGGTATCACACCCCAAGGAAAC");
+        let alignment = align(&text, &dna("GCCGTCAGGCTGGTGTCCG"), &scoring, Mode::Fitting).unwrap();
+        assert_eq!(alignment.score, 5);
+        assert_eq!(alignment.ranges.1, 0..19);
+        assert_eq!(ungapped(&alignment.rows.0), &text[alignment.ranges.0.clone()]);
+    }
+}
@@ -0,0 +1,127 @@
+use std::cmp;
+use std::ops::Range;
+
+/// Edit distances of `pattern` against the text read one residue at a
+/// time: after each residue, `distance` holds the best distance of each
+/// prefix of the pattern against any suffix of the text so far.
+struct Fitting<'a, T: 'a> {
+    pattern: &'a [T],
+    distance: Vec<usize>,
+}
+
+impl<'a, T: PartialEq> Fitting<'a, T> {
+    fn new(pattern: &'a [T]) -> Self {
+        Fitting { pattern, distance: (0..=pattern.len()).collect() }
+    }
+
+    /// Take in the next residue of the text, returning the distance of the
+    /// whole pattern against the best substring ending there.
+    fn push(&mut self, residue: &T) -> usize {
+        let mut diagonal = self.distance[0];
+        for (i, p) in self.pattern.iter().enumerate() {
+            let substituted = diagonal + usize::from(p != residue);
+            diagonal = self.distance[i + 1];
+            self.distance[i + 1] = cmp::min(substituted, cmp::min(self.distance[i], self.distance[i + 1]) + 1);
+        }
+        self.distance[self.pattern.len()]
+    }
+}
+
+/// Lengths of the substrings of `text` ending at `end` within edit distance
+/// `max_distance` of `pattern`. Only a band of cells can be that close:
+/// a suffix of the pattern and a substring whose lengths differ by more
+/// than `max_distance`, for instance, can't be, so nothing outside the band
+/// is filled in and distances are capped just beyond `max_distance`.
+fn lengths_ending_at<T: PartialEq>(pattern: &[T], text: &[T], end: usize, max_distance: usize) -> Vec<usize> {
+    let longest = cmp::min(pattern.len() + max_distance, end);
+    let beyond = max_distance + 1;
+    // Rows are suffixes of the pattern, columns lengths of text[..end]'s suffixes.
+    let mut row: Vec<usize> = (0..=longest).map(|len| cmp::min(len, beyond)).collect();
+    let mut next = vec![beyond; longest + 1];
+    let mut band = 0..=cmp::min(max_distance, longest);
+    for (idx, p) in pattern.iter().rev().enumerate() {
+        let suffix = idx + 1;
+        let (low, high) = (suffix.saturating_sub(max_distance), cmp::min(suffix + max_distance, longest));
+        if low > high {
+            return Vec::new();
+        }
+        if low == 0 {
+            next[0] = cmp::min(suffix, beyond);
+        } else {
+            next[low - 1] = beyond;
+        }
+        for len in cmp::max(low, 1)..=high {
+            let substituted = row[len - 1] + usize::from(*p != text[end - len]);
+            next[len] = cmp::min(beyond, cmp::min(substituted, cmp::min(row[len], next[len - 1]) + 1));
+        }
+        if high < longest {
+            next[high + 1] = beyond;
+        }
+        ::std::mem::swap(&mut row, &mut next);
+        band = low..=high;
+    }
+    band.filter(|&len| len > 0 && row[len] <= max_distance).collect()
+}
+
+/// Every substring of `text` within edit distance `max_distance` of
+/// `motif`, by start and then length. A fitting pass over the text finds
+/// where such substrings end, and a pass back from each end finds where
+/// they start.
+pub fn approximate_occurrences<T: PartialEq>(motif: &[T], text: &[T], max_distance: usize) -> Vec<Range<usize>> {
+    let mut fitting = Fitting::new(motif);
+    let mut found = Vec::new();
+    for (idx, residue) in text.iter().enumerate() {
+        let end = idx + 1;
+        if fitting.push(residue) > max_distance {
+            continue;
+        }
+        found.extend(lengths_ending_at(motif, text, end, max_distance).into_iter().map(|len| end - len..end));
+    }
+    found.sort_by_key(|range| (range.start, range.end));
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ksim_sample() {
+        let found = approximate_occurrences(b"ACGTAG", b"ACGGATCGGCATCGT", 2);
+        assert_eq!(found, vec![0..4, 0..5, 0..6]);
+    }
+
+    fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, x) in a.iter().enumerate() {
+            let mut next = vec![i + 1; b.len() + 1];
+            for (j, y) in b.iter().enumerate() {
+                next[j + 1] = cmp::min(row[j] + usize::from(x != y), cmp::min(row[j + 1], next[j]) + 1);
+            }
+            row = next;
+        }
+        row[b.len()]
+    }
+
+    #[test]
+    fn matches_every_substring() {
+        let text = b"ACGTTGCAACGTAGGATCCAGTACGATTACGGATCAGTTTGACCAGTAGACGATAC";
+        for motif in &[&b"ACGTAG"[..], b"GATTACA", b"TT"] {
+            for k in 0..4 {
+                let expected: Vec<_> = (0..text.len())
+                    .flat_map(|start| (start + 1..=text.len()).map(move |end| start..end))
+                    .filter(|range| edit_distance(motif, &text[range.clone()]) <= k)
+                    .collect();
+                assert_eq!(approximate_occurrences(motif, text, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn exact_and_empty() {
+        assert_eq!(approximate_occurrences(b"AB", b"xABxAB", 0), vec![1..3, 4..6]);
+        assert_eq!(approximate_occurrences(b"AB", b"", 1), Vec::<Range<usize>>::new());
+        assert_eq!(approximate_occurrences(b"AB", b"B", 1), vec![0..1]);
+        assert_eq!(approximate_occurrences(b"", b"AB", 1), vec![0..1, 1..2]);
+    }
+}
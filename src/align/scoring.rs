@@ -0,0 +1,185 @@
+use std::fs;
+use error::{Error, Result};
+use seq::residue::Residue;
+
+/// Scores are looked up by ASCII code.
+const TABLE_SIZE: usize = 128;
+
+const BLOSUM62: &str = include_str!("matrices/BLOSUM62");
+const PAM250: &str = include_str!("matrices/PAM250");
+
+/// IUPAC nucleotide codes, for DNA match/mismatch scoring.
+const NUCLEOTIDES: &str = "ACGTUNRYKMSWBDHV";
+
+/// Names accepted by `SubstitutionMatrix::named`.
+pub const MATRIX_NAMES: &[&str] = &["blosum62", "pam250", "dna"];
+
+/// Largest score or penalty accepted, so that sums over long alignments
+/// stay well clear of the aligners' negative infinity.
+pub const MAX_SCORE: i32 = 1 << 16;
+
+/// Scores for aligning one residue against another, looked up by letter
+/// regardless of case.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubstitutionMatrix {
+    scores: Vec<Option<i32>>,
+}
+
+impl SubstitutionMatrix {
+    fn empty() -> Self {
+        SubstitutionMatrix { scores: vec![None; TABLE_SIZE * TABLE_SIZE] }
+    }
+
+    fn set(&mut self, a: char, b: char, score: i32) {
+        for &a in &[a.to_ascii_uppercase(), a.to_ascii_lowercase()] {
+            for &b in &[b.to_ascii_uppercase(), b.to_ascii_lowercase()] {
+                self.scores[a as usize * TABLE_SIZE + b as usize] = Some(score);
+            }
+        }
+    }
+
+    /// Parse a matrix in NCBI format: `#` comments, a header line of column
+    /// letters, then one row per letter starting with that letter.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut lines = text.lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+        let columns = match lines.next() {
+            Some((number, header)) => letters(header.split_whitespace(), number)?,
+            None => return Err(Error::parse("Substitution matrix is empty")),
+        };
+
+        let mut matrix = SubstitutionMatrix::empty();
+        for (number, line) in lines {
+            let mut fields = line.split_whitespace();
+            let row = letters(fields.next(), number)?[0];
+            let scores = fields
+                .map(|field| field.parse().map_err(|_| Error::parse(format!("line {}: invalid score '{}'", number, field))))
+                .collect::<Result<Vec<i32>>>()?;
+            if let Some(score) = scores.iter().find(|score| score.abs() > MAX_SCORE) {
+                return Err(Error::parse(format!("line {}: score {} is beyond ±{}", number, score, MAX_SCORE)));
+            }
+            if scores.len() != columns.len() {
+                return Err(Error::parse(format!("line {}: expected {} scores, found {}", number, columns.len(), scores.len())));
+            }
+            for (&column, &score) in columns.iter().zip(&scores) {
+                matrix.set(row, column, score);
+            }
+        }
+        Ok(matrix)
+    }
+
+    /// Read an NCBI format matrix file.
+    pub fn from_file(path: &str) -> Result<Self> {
+        SubstitutionMatrix::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn blosum62() -> Self {
+        SubstitutionMatrix::parse(BLOSUM62).expect("BLOSUM62 is well formed")
+    }
+
+    pub fn pam250() -> Self {
+        SubstitutionMatrix::parse(PAM250).expect("PAM250 is well formed")
+    }
+
+    /// `matches` for identical letters and `mismatches` for any other pair
+    /// drawn from `letters`.
+    pub fn identity(letters: &str, matches: i32, mismatches: i32) -> Self {
+        let mut matrix = SubstitutionMatrix::empty();
+        for a in letters.chars() {
+            for b in letters.chars() {
+                matrix.set(a, b, if a == b { matches } else { mismatches });
+            }
+        }
+        matrix
+    }
+
+    /// Match/mismatch scoring over the IUPAC nucleotide codes. Ambiguity
+    /// codes only match themselves.
+    pub fn dna(matches: i32, mismatches: i32) -> Self {
+        SubstitutionMatrix::identity(NUCLEOTIDES, matches, mismatches)
+    }
+
+    /// A built-in matrix from `MATRIX_NAMES`, with `dna` scoring 1 for a
+    /// match and -1 for a mismatch, or else an NCBI matrix file.
+    pub fn named(name: &str) -> Result<Self> {
+        match name {
+            "blosum62" => Ok(SubstitutionMatrix::blosum62()),
+            "pam250" => Ok(SubstitutionMatrix::pam250()),
+            "dna" => Ok(SubstitutionMatrix::dna(1, -1)),
+            path => SubstitutionMatrix::from_file(path),
+        }
+    }
+
+    pub fn score(&self, a: char, b: char) -> Option<i32> {
+        if a.is_ascii() && b.is_ascii() {
+            self.scores[a as usize * TABLE_SIZE + b as usize]
+        } else {
+            None
+        }
+    }
+
+    /// Fail on the first residue the matrix has no scores for.
+    pub fn check<T: Residue>(&self, seq: &[T]) -> Result<()> {
+        match seq.iter().map(|r| r.to_char()).find(|&c| self.score(c, c).is_none()) {
+            Some(c) => Err(Error::domain(format!("Residue '{}' is not in the substitution matrix", c))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn letters<'a, I: IntoIterator<Item = &'a str>>(fields: I, line: usize) -> Result<Vec<char>> {
+    let letters = fields.into_iter()
+        .map(|field| {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii() => Ok(c),
+                _ => Err(Error::parse(format!("line {}: expected a single letter, found '{}'", line, field))),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if letters.is_empty() {
+        return Err(Error::parse(format!("line {}: expected a letter", line)));
+    }
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seq::translation::TranslatedCodon;
+
+    #[test]
+    fn built_in_matrices() {
+        let blosum = SubstitutionMatrix::blosum62();
+        assert_eq!(blosum.score('W', 'W'), Some(11));
+        assert_eq!(blosum.score('a', 'R'), Some(-1));
+        assert_eq!(blosum.score('*', '*'), Some(1));
+        assert_eq!(SubstitutionMatrix::pam250().score('C', 'C'), Some(12));
+        for matrix in &[blosum, SubstitutionMatrix::pam250()] {
+            for a in "ARNDCQEGHILKMFPSTWYVBZX*".chars() {
+                for b in "ARNDCQEGHILKMFPSTWYVBZX*".chars() {
+                    assert_eq!(matrix.score(a, b), matrix.score(b, a));
+                }
+            }
+        }
+        assert_eq!(SubstitutionMatrix::dna(2, -3).score('A', 'g'), Some(-3));
+        assert_eq!(SubstitutionMatrix::dna(2, -3).score('E', 'E'), None);
+    }
+
+    #[test]
+    fn ncbi_format() {
+        let matrix = SubstitutionMatrix::parse("# tiny\n   A  C\nA  1 -2\nC -2  3\n").unwrap();
+        assert_eq!(matrix.score('C', 'C'), Some(3));
+        assert_eq!(matrix.score('A', 'G'), None);
+        assert!(matrix.check(&[TranslatedCodon::A, TranslatedCodon::C]).is_ok());
+        assert!(matrix.check(&[TranslatedCodon::W]).is_err());
+
+        assert!(SubstitutionMatrix::parse("").is_err());
+        assert!(SubstitutionMatrix::parse("A C\nA 1\n").is_err());
+        assert!(SubstitutionMatrix::parse("A C\nA 1 x\n").is_err());
+        assert!(SubstitutionMatrix::parse("AC\nA 1\n").is_err());
+        assert!(SubstitutionMatrix::parse("A\nA -2000000000\n").is_err());
+    }
+}
@@ -0,0 +1,232 @@
+//! Run problems against stored sample answers.
+//!
+//! A case is a `<name>.expected` file in `<root>/<problem id>/`. Its input is
+//! the sibling file with the same stem (`dna.txt` for `dna.expected`), and
+//! any other arguments come from `<name>.args`, one `name value` pair (or a
+//! bare flag name) per line. A case for a problem that reads an input fails
+//! without one, and more than one candidate input is an error.
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use error::{Error, Result};
+use problem::{Args, Solver, INPUT};
+use problems;
+
+const EXPECTED_EXTENSION: &str = "expected";
+const ARGS_EXTENSION: &str = "args";
+
+/// Absolute difference allowed between fractional numbers in an answer.
+pub const TOLERANCE: f64 = 1e-3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Case {
+    pub problem: String,
+    pub name: String,
+    pub input: Option<PathBuf>,
+    pub args_file: Option<PathBuf>,
+    pub expected: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Pass,
+    /// The solver ran but gave a different answer.
+    Mismatch { expected: String, actual: String },
+    /// The case couldn't be run at all.
+    Error(String),
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        *self == Outcome::Pass
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "PASS"),
+            Outcome::Mismatch { expected, actual } =>
+                write!(f, "FAIL  expected {:?}, got {:?}", expected.trim(), actual.trim()),
+            Outcome::Error(message) => write!(f, "FAIL  {}", message),
+        }
+    }
+}
+
+/// Find every case under `root`, sorted by problem and case name.
+pub fn discover<P: AsRef<Path>>(root: P) -> io::Result<Vec<Case>> {
+    let mut cases = Vec::new();
+    for dir in fs::read_dir(root)? {
+        let dir = dir?.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        let problem = file_name(&dir);
+        let files = fs::read_dir(&dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        for expected in files.iter().filter(|f| has_extension(f, EXPECTED_EXTENSION)) {
+            let name = stem(expected);
+            let sibling = |f: &&PathBuf| stem(f) == name && !has_extension(f, EXPECTED_EXTENSION);
+            let mut inputs = files.iter().filter(sibling).filter(|f| !has_extension(f, ARGS_EXTENSION));
+            let input = inputs.next().cloned();
+            if let Some(other) = inputs.next() {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, format!(
+                    "Case {}/{} has more than one input file, including {} and {}",
+                    problem, name, file_name(input.as_ref().expect("first input")), file_name(other))));
+            }
+            cases.push(Case {
+                problem: problem.clone(),
+                name: name.clone(),
+                input,
+                args_file: files.iter().filter(sibling).find(|f| has_extension(f, ARGS_EXTENSION)).cloned(),
+                expected: expected.clone(),
+            });
+        }
+    }
+    cases.sort_by(|a, b| (&a.problem, &a.name).cmp(&(&b.problem, &b.name)));
+    Ok(cases)
+}
+
+/// Solve a single case and compare it with its expected answer.
+pub fn run(case: &Case) -> Outcome {
+    let solver = match problems::find(&case.problem) {
+        Some(solver) => solver,
+        None => return Outcome::Error(format!("no problem called '{}'", case.problem)),
+    };
+    match solve(solver.as_ref(), case) {
+        Ok((expected, actual)) => {
+            if outputs_match(&expected, &actual) {
+                Outcome::Pass
+            } else {
+                Outcome::Mismatch { expected, actual }
+            }
+        }
+        Err(err) => Outcome::Error(err.to_string()),
+    }
+}
+
+fn solve(solver: &dyn Solver, case: &Case) -> Result<(String, String)> {
+    let mut args = match case.args_file {
+        Some(ref path) => parse_args(&fs::read_to_string(path)?),
+        None => Args::new(),
+    };
+    // Without an input file the solver would fall back to standard input.
+    match case.input {
+        Some(ref input) => args = args.with_value(INPUT, input.to_string_lossy()),
+        None if solver.args().iter().any(|spec| spec.name == INPUT) =>
+            return Err(Error::usage(format!("No input file for case '{}'", case.name))),
+        None => {}
+    }
+    let expected = fs::read_to_string(&case.expected)?;
+    Ok((expected, solver.run(&args)?))
+}
+
+/// Parse an `.args` file: `name value` sets a value, a lone `name` sets a flag.
+pub fn parse_args(text: &str) -> Args {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .fold(Args::new(), |args, line| {
+            let mut fields = line.splitn(2, char::is_whitespace);
+            let name = fields.next().expect("line is not empty");
+            match fields.next() {
+                Some(value) => args.with_value(name, value.trim()),
+                None => args.with_flag(name),
+            }
+        })
+}
+
+/// Compare answers token by token, allowing `TOLERANCE` between fractional numbers.
+pub fn outputs_match(expected: &str, actual: &str) -> bool {
+    let expected: Vec<&str> = expected.split_whitespace().collect();
+    let actual: Vec<&str> = actual.split_whitespace().collect();
+    expected.len() == actual.len() && expected.iter().zip(&actual).all(|(e, a)| tokens_match(e, a))
+}
+
+fn tokens_match(expected: &str, actual: &str) -> bool {
+    if expected == actual {
+        return true;
+    }
+    if !is_fractional(expected) && !is_fractional(actual) {
+        return false;
+    }
+    match (expected.parse::<f64>(), actual.parse::<f64>()) {
+        (Ok(e), Ok(a)) => (e - a).abs() <= TOLERANCE,
+        _ => false,
+    }
+}
+
+fn is_fractional(token: &str) -> bool {
+    token.contains(['.', 'e', 'E'])
+}
+
+/// Lay out the results as a table, one case per row.
+pub fn format_table(results: &[(Case, Outcome)]) -> String {
+    let width = |f: &dyn Fn(&Case) -> usize, title: &str| {
+        results.iter().map(|(case, _)| f(case)).chain(Some(title.len())).max().unwrap_or(0)
+    };
+    let problem_width = width(&|case| case.problem.len(), "problem");
+    let name_width = width(&|case| case.name.len(), "case");
+
+    let mut table = format!("{:pw$}  {:nw$}  result\n", "problem", "case", pw = problem_width, nw = name_width);
+    for (case, outcome) in results {
+        table += &format!("{:pw$}  {:nw$}  {}\n", case.problem, case.name, outcome, pw = problem_width, nw = name_width);
+    }
+    let passed = results.iter().filter(|(_, outcome)| outcome.passed()).count();
+    table += &format!("{} of {} passed", passed, results.len());
+    table
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn stem(path: &Path) -> String {
+    path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default()
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension().is_some_and(|ext| ext == extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tolerant_comparison() {
+        assert!(outputs_match("Rosalind_0808\n60.919540\n", "Rosalind_0808\n60.91954022988506"));
+        assert!(outputs_match("0.78333", "0.7833333333333333"));
+        assert!(!outputs_match("0.78333", "0.7850"));
+        assert!(!outputs_match("20 12 17 21", "20 12 17 22"));
+        assert!(!outputs_match("19", "19 1"));
+    }
+
+    #[test]
+    fn args_files() {
+        let args = parse_args("# sample\ngenerations 5\nfecundity  3\nstrict\n");
+        assert_eq!(args.integer("generations").unwrap(), 5);
+        assert_eq!(args.integer("fecundity").unwrap(), 3);
+        assert!(args.flag("strict"));
+    }
+
+    #[test]
+    fn input_files_are_required_and_unambiguous() {
+        let root = ::std::env::temp_dir().join(format!("rustalind-check-{}", ::std::process::id()));
+        let dir = root.join("dna");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("x.expected"), "1 0 0 0\n").unwrap();
+        let cases = discover(&root).unwrap();
+        assert_eq!(cases[0].input, None);
+        assert!(!run(&cases[0]).passed());
+
+        fs::write(dir.join("x.txt"), "A\n").unwrap();
+        assert!(run(&discover(&root).unwrap()[0]).passed());
+        fs::write(dir.join("x.fa"), ">x\nA\n").unwrap();
+        assert!(discover(&root).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,113 @@
+//! Solving downloaded Rosalind datasets, which are named `rosalind_<id>.txt`.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use error::{Error, Result};
+use problem::{ArgKind, ArgSpec, Args, INPUT};
+use problems;
+
+const PREFIX: &str = "rosalind_";
+const EXTENSION: &str = "txt";
+
+/// The problem id in a dataset's file name, e.g. `gc` for `rosalind_gc.txt`.
+pub fn problem_id<P: AsRef<Path>>(path: P) -> Option<String> {
+    let path = path.as_ref();
+    if path.extension().is_none_or(|ext| ext != EXTENSION) {
+        return None;
+    }
+    let stem = path.file_stem()?.to_str()?;
+    let id = stem.strip_prefix(PREFIX)?;
+    if id.is_empty() {
+        None
+    } else {
+        Some(id.to_lowercase())
+    }
+}
+
+/// Where the answer to a dataset goes: `<id>.out` next to the dataset.
+pub fn answer_path<P: AsRef<Path>>(path: P, id: &str) -> PathBuf {
+    path.as_ref().with_file_name(format!("{}.out", id))
+}
+
+/// Expand `paths` into dataset files, searching directories recursively.
+/// Files named explicitly are kept even if their name isn't a dataset's.
+pub fn find_datasets<P: AsRef<Path>>(paths: &[P]) -> io::Result<Vec<PathBuf>> {
+    let mut datasets = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        if path.is_dir() {
+            search_dir(path, &mut datasets)?;
+        } else {
+            datasets.push(path.to_path_buf());
+        }
+    }
+    Ok(datasets)
+}
+
+fn search_dir(dir: &Path, datasets: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            search_dir(&path, datasets)?;
+        } else if problem_id(&path).is_some() {
+            datasets.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Turn a dataset into arguments for a problem that takes `specs`.
+///
+/// Problems with required integer parameters read them, in the order they are
+/// declared, from the whitespace-separated numbers in the file. Any other
+/// problem gets the file as its `INPUT`.
+pub fn default_args(specs: &[ArgSpec], path: &Path) -> Result<Args> {
+    let integers: Vec<_> = specs.iter().filter(|spec| spec.kind == ArgKind::Integer && spec.required).collect();
+    if integers.is_empty() {
+        return Ok(Args::new().with_value(INPUT, path.to_string_lossy()));
+    }
+
+    let text = fs::read_to_string(path)?;
+    let values: Vec<&str> = text.split_whitespace().collect();
+    if values.len() != integers.len() {
+        return Err(Error::parse(format!(
+            "expected {} integers ({}), found {} values",
+            integers.len(),
+            integers.iter().map(|spec| spec.name).collect::<Vec<_>>().join(", "),
+            values.len())));
+    }
+    Ok(integers.iter().zip(values).fold(Args::new(), |args, (spec, value)| args.with_value(spec.name, value)))
+}
+
+/// Solve one dataset and write its answer file, returning the answer's path.
+pub fn solve<P: AsRef<Path>>(path: P) -> Result<PathBuf> {
+    let path = path.as_ref();
+    let id = problem_id(path).ok_or_else(|| Error::usage(
+        format!("file name is not of the form {}<id>.{}", PREFIX, EXTENSION)))?;
+    let solver = problems::find(&id).ok_or_else(|| Error::usage(
+        format!("no problem called '{}'", id)))?;
+
+    let answer = solver.run(&solver.dataset_args(path)?)?;
+    let out = answer_path(path, &id);
+    fs::write(&out, answer + "\n")?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_from_file_names() {
+        assert_eq!(problem_id("test-data/gc/rosalind_gc.txt"), Some("gc".to_owned()));
+        assert_eq!(problem_id("rosalind_IPRB.txt"), Some("iprb".to_owned()));
+        assert_eq!(problem_id("rosalind_.txt"), None);
+        assert_eq!(problem_id("gc.txt"), None);
+        assert_eq!(problem_id("rosalind_gc.fasta"), None);
+        assert_eq!(answer_path("data/rosalind_gc.txt", "gc"), PathBuf::from("data/gc.out"));
+    }
+}
@@ -0,0 +1,98 @@
+use error::Result;
+
+/// Distances between every pair of a set of labelled sequences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceMatrix {
+    labels: Vec<String>,
+    /// Row by row; symmetric with zeros on the diagonal.
+    distances: Vec<Vec<f64>>,
+}
+
+/// Width strict PHYLIP gives each label; shorter labels are still padded to it.
+const PHYLIP_LABEL_WIDTH: usize = 10;
+
+impl DistanceMatrix {
+    /// Measure every pair of `items` with `distance`, which should be
+    /// symmetric, so each pair is only measured once.
+    pub fn build<T, F>(items: &[(String, T)], distance: F) -> Result<Self>
+        where F: Fn(&T, &T) -> Result<f64>
+    {
+        let mut distances = vec![vec![0.0; items.len()]; items.len()];
+        for i in 0..items.len() {
+            for j in i + 1..items.len() {
+                let d = distance(&items[i].1, &items[j].1)?;
+                distances[i][j] = d;
+                distances[j][i] = d;
+            }
+        }
+        Ok(DistanceMatrix { labels: items.iter().map(|(label, _)| label.clone()).collect(), distances })
+    }
+
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        self.distances[i][j]
+    }
+
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Rows of distances to `decimals` places, as Rosalind's PDST expects.
+    pub fn to_table(&self, decimals: usize) -> String {
+        self.distances.iter()
+            .map(|row| row.iter().map(|d| format!("{:.*}", decimals, d)).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The square relaxed PHYLIP distance matrix format: the number of
+    /// sequences, then each full label padded to the longest (and to at
+    /// least ten characters) followed by its row.
+    pub fn to_phylip(&self, decimals: usize) -> String {
+        let width = self.labels.iter()
+            .map(|label| label.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(PHYLIP_LABEL_WIDTH);
+        let mut phylip = format!("{:5}", self.len());
+        for (label, row) in self.labels.iter().zip(&self.distances) {
+            phylip += &format!("\n{:width$}", label, width = width);
+            for d in row {
+                phylip += &format!(" {:.*}", decimals, d);
+            }
+        }
+        phylip
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use distance::p_distance;
+
+    #[test]
+    fn pairwise_distances() {
+        // The PDST sample dataset.
+        let seqs: Vec<(String, Vec<u8>)> = [("Rosalind_9499", "TTTCCATTTA"), ("Rosalind_0942", "GATTCATTTC"),
+                                            ("Rosalind_6568", "TTTCCATTTT"), ("Rosalind_1833", "GTTCCATTTA")]
+            .iter()
+            .map(|&(id, seq)| (id.to_owned(), seq.as_bytes().to_vec()))
+            .collect();
+        let matrix = DistanceMatrix::build(&seqs, |a, b| p_distance(a, b)).unwrap();
+        assert_eq!(matrix.to_table(5).lines().nth(1), Some("0.40000 0.00000 0.40000 0.30000"));
+        assert_eq!(matrix.to_phylip(4).lines().take(2).collect::<Vec<_>>(),
+                   vec!["    4", "Rosalind_9499 0.0000 0.4000 0.1000 0.1000"]);
+        let short = DistanceMatrix::build(&[("a".to_owned(), vec![1u8])], |a, b| p_distance(a, b)).unwrap();
+        assert_eq!(short.to_phylip(1), "    1\na          0.0");
+
+        let uneven = vec![("a".to_owned(), vec![1, 2]), ("b".to_owned(), vec![1])];
+        assert!(DistanceMatrix::build(&uneven, |a, b| p_distance(a, b)).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Distances between aligned sequences of equal length, from raw mismatch
+//! counts to evolutionary models that correct for multiple substitutions.
+
+use std::fmt;
+use std::str::FromStr;
+use error::{Error, Result};
+use seq::nuc::DNA;
+
+pub mod matrix;
+
+pub use self::matrix::DistanceMatrix;
+
+fn check_lengths<T>(seq1: &[T], seq2: &[T]) -> Result<()> {
+    if seq1.len() == seq2.len() {
+        Ok(())
+    } else {
+        Err(Error::domain(format!("Sequences have different lengths ({} and {})", seq1.len(), seq2.len())))
+    }
+}
+
+/// Number of positions at which two sequences of the same length differ.
+pub fn hamming_distance<T: PartialEq>(seq1: &[T], seq2: &[T]) -> Result<usize> {
+    check_lengths(seq1, seq2)?;
+    Ok(seq1.iter()
+        .zip(seq2.iter())
+        .filter(|a| a.0 != a.1)
+        .count())
+}
+
+/// As `hamming_distance`, but ambiguity codes match any base they could
+/// stand for: `N` matches everything, and `R` matches `A`, `G` and `S`.
+/// Gaps only match gaps.
+pub fn ambiguous_hamming_distance(seq1: &[DNA], seq2: &[DNA]) -> Result<usize> {
+    check_lengths(seq1, seq2)?;
+    Ok(seq1.iter()
+        .zip(seq2.iter())
+        .filter(|&(&a, &b)| a != b && a & b == DNA::Gap)
+        .count())
+}
+
+/// Proportion of positions at which two sequences differ.
+pub fn p_distance<T: PartialEq>(seq1: &[T], seq2: &[T]) -> Result<f64> {
+    let differences = hamming_distance(seq1, seq2)?;
+    if seq1.is_empty() {
+        return Err(Error::domain("Can't compare empty sequences"));
+    }
+    Ok(differences as f64 / seq1.len() as f64)
+}
+
+/// Point substitutions between two aligned DNA sequences. Only sites where
+/// both sequences have an unambiguous base are counted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Substitutions {
+    /// Purine to purine or pyrimidine to pyrimidine, such as `A` to `G`.
+    pub transitions: usize,
+    /// Purine to pyrimidine or back, such as `A` to `C`.
+    pub transversions: usize,
+    /// Sites compared, matching or not.
+    pub sites: usize,
+}
+
+fn is_unambiguous(base: DNA) -> bool {
+    matches!(base, DNA::A | DNA::C | DNA::G | DNA::T)
+}
+
+impl Substitutions {
+    pub fn count(seq1: &[DNA], seq2: &[DNA]) -> Result<Self> {
+        check_lengths(seq1, seq2)?;
+        let mut substitutions = Substitutions::default();
+        for (&a, &b) in seq1.iter().zip(seq2) {
+            if !is_unambiguous(a) || !is_unambiguous(b) {
+                continue;
+            }
+            substitutions.sites += 1;
+            if a == b {
+                continue;
+            }
+            // Both purines fall within R, both pyrimidines within Y.
+            if a | b == DNA::R || a | b == DNA::Y {
+                substitutions.transitions += 1;
+            } else {
+                substitutions.transversions += 1;
+            }
+        }
+        Ok(substitutions)
+    }
+
+    /// Transitions per transversion (TRAN).
+    pub fn ratio(&self) -> Result<f64> {
+        match self.transversions {
+            0 => Err(Error::domain("No transversions, so the transition/transversion ratio is undefined")),
+            transversions => Ok(self.transitions as f64 / transversions as f64),
+        }
+    }
+
+    fn proportions(&self) -> Result<(f64, f64)> {
+        match self.sites {
+            0 => Err(Error::domain("No sites where both sequences have an unambiguous base")),
+            sites => Ok((self.transitions as f64 / sites as f64, self.transversions as f64 / sites as f64)),
+        }
+    }
+}
+
+/// `ln(x)` for a model's correction term, which must be positive.
+fn corrected(x: f64, model: &str) -> Result<f64> {
+    if x > 0.0 {
+        Ok(x.ln())
+    } else {
+        Err(Error::domain(format!("Sequences are too divergent for the {} model", model)))
+    }
+}
+
+/// Jukes-Cantor distance: expected substitutions per site when every base
+/// changes to every other at the same rate.
+pub fn jukes_cantor(seq1: &[DNA], seq2: &[DNA]) -> Result<f64> {
+    let (transitions, transversions) = Substitutions::count(seq1, seq2)?.proportions()?;
+    let p = transitions + transversions;
+    Ok(-0.75 * corrected(1.0 - 4.0 / 3.0 * p, "Jukes-Cantor")?)
+}
+
+/// Kimura 2-parameter distance, which lets transitions and transversions
+/// happen at different rates.
+pub fn kimura_2p(seq1: &[DNA], seq2: &[DNA]) -> Result<f64> {
+    let (p, q) = Substitutions::count(seq1, seq2)?.proportions()?;
+    Ok(-0.5 * corrected(1.0 - 2.0 * p - q, "Kimura 2-parameter")? - 0.25 * corrected(1.0 - 2.0 * q, "Kimura 2-parameter")?)
+}
+
+/// A way of measuring the distance between two DNA sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    /// Differing sites, with ambiguity codes matching what they could stand for.
+    Hamming,
+    P,
+    JukesCantor,
+    Kimura2P,
+}
+
+pub const MODEL_NAMES: &[&str] = &["hamming", "p", "jc", "k2p"];
+
+impl Model {
+    pub fn distance(self, seq1: &[DNA], seq2: &[DNA]) -> Result<f64> {
+        match self {
+            Model::Hamming => ambiguous_hamming_distance(seq1, seq2).map(|d| d as f64),
+            Model::P => p_distance(seq1, seq2),
+            Model::JukesCantor => jukes_cantor(seq1, seq2),
+            Model::Kimura2P => kimura_2p(seq1, seq2),
+        }
+    }
+}
+
+impl FromStr for Model {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "hamming" => Ok(Model::Hamming),
+            "p" => Ok(Model::P),
+            "jc" => Ok(Model::JukesCantor),
+            "k2p" => Ok(Model::Kimura2P),
+            _ => Err(Error::usage(format!("Unknown distance model '{}', expected one of {}", s, MODEL_NAMES.join(", ")))),
+        }
+    }
+}
+
+impl fmt::Display for Model {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Model::Hamming => "hamming",
+            Model::P => "p",
+            Model::JukesCantor => "jc",
+            Model::Kimura2P => "k2p",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use problem::parse_sequence;
+
+    fn dna(s: &str) -> Vec<DNA> {
+        parse_sequence(s, DNA::from_iupac).unwrap()
+    }
+
+    //noinspection ALL
+    #[test]
+    fn basic_distance() {
+        let seq1: Vec<DNA> = "GAGCCTACTAACGGGAT".chars().map(DNA::from).collect();
+        let seq2: Vec<DNA> = "CATCGTAATGACGGCCT".chars().map(DNA::from).collect();
+        assert_eq!(7, hamming_distance(&seq1, &seq2).unwrap());
+        assert!(hamming_distance(&seq1, &seq2[1..]).is_err());
+    }
+
+    #[test]
+    fn ambiguity_codes() {
+        assert_eq!(hamming_distance(&dna("ACGTN"), &dna("ACGTA")).unwrap(), 1);
+        assert_eq!(ambiguous_hamming_distance(&dna("ACGTN"), &dna("ACGTA")).unwrap(), 0);
+        assert_eq!(ambiguous_hamming_distance(&dna("RYN-"), &dna("GCT-")).unwrap(), 0);
+        assert_eq!(ambiguous_hamming_distance(&dna("RY-"), &dna("CAA")).unwrap(), 3);
+    }
+
+    #[test]
+    fn substitution_models() {
+        // The TRAN sample dataset.
+        let seq1 = dna("GCAACGCACAACGAAAACCCTTAGGGACTGGATTATTTCGTGATCGTTGTAGTTATTGGAAGTACGGGCATCAACCCAGTT");
+        let seq2 = dna("TTATCTGACAAAGAAAGCCGTCAACGGCTGGATAATTTCGCGATCGTGCTGGTTACTGGCGGTACGAGTGTTCCTTTGGGT");
+        let substitutions = Substitutions::count(&seq1, &seq2).unwrap();
+        assert!((substitutions.ratio().unwrap() - 1.21428571429).abs() < 1e-9);
+
+        let (a, b) = (dna("TTTCCATTTA"), dna("GATTCATTTC"));
+        assert_eq!(p_distance(&a, &b).unwrap(), 0.4);
+        assert!((jukes_cantor(&a, &b).unwrap() - 0.5716).abs() < 1e-4);
+        // One transition and three transversions in ten sites.
+        assert!((kimura_2p(&a, &b).unwrap() - (-0.5 * 0.5f64.ln() - 0.25 * 0.4f64.ln())).abs() < 1e-12);
+        assert!(kimura_2p(&dna("AC"), &dna("GC")).is_err());
+        assert!(jukes_cantor(&dna("AC"), &dna("CA")).is_err());
+        assert!(Substitutions::count(&a, &a).unwrap().ratio().is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use std::error;
+use std::fmt;
+use std::io;
+use std::result;
+use io::{FastaError, FastqError, InvalidResidue};
+use tree::NewickError;
+
+/// Everything that can stop a problem from being solved.
+#[derive(Debug)]
+pub enum Error {
+    /// Missing or malformed command line arguments.
+    Usage(String),
+    /// Failure reading or writing a file.
+    Io(io::Error),
+    /// Input data that isn't in the expected format.
+    Parse(String),
+    /// Well-formed input with no answer, such as a population too small to
+    /// draw from or a count too large to represent.
+    Domain(String),
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+impl Error {
+    pub fn usage<S: Into<String>>(message: S) -> Self {
+        Error::Usage(message.into())
+    }
+
+    pub fn parse<S: Into<String>>(message: S) -> Self {
+        Error::Parse(message.into())
+    }
+
+    pub fn domain<S: Into<String>>(message: S) -> Self {
+        Error::Domain(message.into())
+    }
+
+    /// Process exit status for the command line: 2 for usage, 3 for I/O,
+    /// 4 for parse and 5 for domain errors.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Usage(_) => 2,
+            Error::Io(_) => 3,
+            Error::Parse(_) => 4,
+            Error::Domain(_) => 5,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::Io(err) => write!(f, "{}", err),
+            Error::Parse(message) => write!(f, "{}", message),
+            Error::Domain(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Readers in this crate report bad data as `InvalidData` and bad arguments
+/// as `InvalidInput`, so those kinds keep their meaning here.
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::InvalidData => Error::Parse(err.to_string()),
+            io::ErrorKind::InvalidInput => Error::Usage(err.to_string()),
+            _ => Error::Io(err),
+        }
+    }
+}
+
+impl From<FastaError> for Error {
+    fn from(err: FastaError) -> Self {
+        match err {
+            FastaError::Io(err) => Error::Io(err),
+            err => Error::Parse(err.to_string()),
+        }
+    }
+}
+
+impl From<FastqError> for Error {
+    fn from(err: FastqError) -> Self {
+        match err {
+            FastqError::Io(err) => Error::Io(err),
+            err => Error::Parse(err.to_string()),
+        }
+    }
+}
+
+impl From<InvalidResidue> for Error {
+    fn from(err: InvalidResidue) -> Self {
+        Error::Parse(err.to_string())
+    }
+}
+
+impl From<NewickError> for Error {
+    fn from(err: NewickError) -> Self {
+        Error::Parse(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_error_kinds() {
+        let parse = Error::from(io::Error::new(io::ErrorKind::InvalidData, "bad residue"));
+        assert_eq!(parse.exit_code(), 4);
+        assert_eq!(parse.to_string(), "bad residue");
+        assert_eq!(Error::from(io::Error::new(io::ErrorKind::NotFound, "gone")).exit_code(), 3);
+        assert_eq!(Error::from(FastaError::MissingHeader { line: 1 }).exit_code(), 4);
+    }
+}
@@ -0,0 +1,159 @@
+use std::error;
+use std::fmt;
+use bit_set::BitSet;
+use io::Record;
+use seq::nuc::DNA;
+use seq::packed::PackedDna;
+use seq::residue::Residue;
+use seq::rna::Rna;
+use seq::translation::TranslatedCodon;
+
+/// What to do with lowercase (soft-masked) residues.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoftMasking {
+    /// Read lowercase residues as their uppercase equivalents.
+    Uppercase,
+    /// As `Uppercase`, but also record the lowercase positions in `Typed::mask`.
+    Track,
+    /// Treat lowercase residues as invalid characters.
+    Reject,
+}
+
+/// What to do with characters outside the target alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidCharacters {
+    Reject,
+    /// Replace them with the alphabet's wildcard, `N` or `X`.
+    Convert,
+}
+
+/// Options for turning a `Record`'s text into a typed sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conversion {
+    pub soft_masking: SoftMasking,
+    pub invalid: InvalidCharacters,
+}
+
+impl Default for Conversion {
+    fn default() -> Self {
+        Conversion {
+            soft_masking: SoftMasking::Uppercase,
+            invalid: InvalidCharacters::Reject,
+        }
+    }
+}
+
+/// A typed sequence converted from a `Record`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Typed<S> {
+    pub id: String,
+    pub seq: S,
+    /// Positions that were lowercase, when converted with `SoftMasking::Track`.
+    pub mask: Option<BitSet>,
+}
+
+/// A character that doesn't belong to the requested alphabet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidResidue {
+    pub id: String,
+    /// Zero-based offset into the sequence.
+    pub position: usize,
+    pub ch: char,
+}
+
+impl fmt::Display for InvalidResidue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "record '{}', position {}: invalid residue {:?}", self.id, self.position + 1, self.ch)
+    }
+}
+
+impl error::Error for InvalidResidue {}
+
+impl Record {
+    pub fn to_dna(&self, conversion: &Conversion) -> Result<Typed<Vec<DNA>>, InvalidResidue> {
+        self.convert(conversion, DNA::from_iupac, DNA::N)
+    }
+
+    pub fn to_packed_dna(&self, conversion: &Conversion) -> Result<Typed<PackedDna>, InvalidResidue> {
+        let typed = self.to_dna(conversion)?;
+        Ok(Typed { id: typed.id, seq: typed.seq.into_iter().collect(), mask: typed.mask })
+    }
+
+    pub fn to_rna(&self, conversion: &Conversion) -> Result<Typed<Rna>, InvalidResidue> {
+        let typed = self.convert(conversion, Rna::base_from_char, DNA::N)?;
+        Ok(Typed { id: typed.id, seq: Rna::from(typed.seq), mask: typed.mask })
+    }
+
+    /// Residues are read by `Residue::from_char`, as alignments are.
+    pub fn to_protein(&self, conversion: &Conversion) -> Result<Typed<Vec<TranslatedCodon>>, InvalidResidue> {
+        self.convert(conversion, TranslatedCodon::from_char, TranslatedCodon::X)
+    }
+
+    fn convert<T, F>(&self, conversion: &Conversion, parse: F, wildcard: T) -> Result<Typed<Vec<T>>, InvalidResidue>
+        where T: Copy, F: Fn(char) -> Option<T>
+    {
+        let mut seq = Vec::with_capacity(self.seq.len());
+        let mut mask = match conversion.soft_masking {
+            SoftMasking::Track => Some(BitSet::new()),
+            _ => None,
+        };
+        let invalid = |position, ch| InvalidResidue { id: self.id.clone(), position, ch };
+
+        for (position, ch) in self.seq.chars().enumerate() {
+            if ch.is_ascii_lowercase() {
+                match mask {
+                    Some(ref mut mask) => { mask.insert(position); }
+                    None if conversion.soft_masking == SoftMasking::Reject => return Err(invalid(position, ch)),
+                    None => {}
+                }
+            }
+            let residue = match (parse(ch.to_ascii_uppercase()), conversion.invalid) {
+                (Some(residue), _) => residue,
+                (None, InvalidCharacters::Convert) => wildcard,
+                (None, InvalidCharacters::Reject) => return Err(invalid(position, ch)),
+            };
+            seq.push(residue);
+        }
+
+        Ok(Typed { id: self.id.clone(), seq, mask })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(seq: &str) -> Record {
+        Record { id: "r1".to_owned(), seq: seq.to_owned() }
+    }
+
+    #[test]
+    fn soft_masked_dna() {
+        let conversion = Conversion { soft_masking: SoftMasking::Track, ..Conversion::default() };
+        let typed = record("ACgtN").to_dna(&conversion).unwrap();
+        assert_eq!(typed.seq, vec![DNA::A, DNA::C, DNA::G, DNA::T, DNA::N]);
+        assert_eq!(typed.mask.unwrap().iter().collect::<Vec<_>>(), vec![2, 3]);
+
+        let conversion = Conversion { soft_masking: SoftMasking::Reject, ..Conversion::default() };
+        assert_eq!(record("ACgt").to_dna(&conversion).unwrap_err().position, 2);
+    }
+
+    #[test]
+    fn invalid_characters() {
+        let err = record("ACXT").to_packed_dna(&Conversion::default()).unwrap_err();
+        assert_eq!(err.to_string(), "record 'r1', position 3: invalid residue 'X'");
+
+        let conversion = Conversion { invalid: InvalidCharacters::Convert, ..Conversion::default() };
+        let packed = record("ACXT").to_packed_dna(&conversion).unwrap().seq;
+        assert_eq!(packed.get(2), Some(DNA::N));
+    }
+
+    #[test]
+    fn rna_and_protein() {
+        assert_eq!(record("gaUC").to_rna(&Conversion::default()).unwrap().seq.to_string(), "GAUC");
+        assert!(record("GATC").to_rna(&Conversion::default()).is_err());
+        let protein = record("MAmz*").to_protein(&Conversion { invalid: InvalidCharacters::Convert, ..Conversion::default() });
+        assert_eq!(protein.unwrap().seq, vec![
+            TranslatedCodon::M, TranslatedCodon::A, TranslatedCodon::M, TranslatedCodon::X, TranslatedCodon::Stop]);
+    }
+}
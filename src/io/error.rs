@@ -0,0 +1,110 @@
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Why a FASTA file could not be read. Line and column numbers start at 1.
+#[derive(Debug)]
+pub enum FastaError {
+    Io(io::Error),
+    /// Sequence data (or junk) was found where a `>` header was expected.
+    MissingHeader { line: usize },
+    /// A header was not followed by any sequence.
+    EmptySequence { id: String, line: usize },
+    InvalidCharacter { line: usize, col: usize, ch: char },
+    /// The same id was used by more than one record.
+    DuplicateId { id: String, line: usize },
+}
+
+impl fmt::Display for FastaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FastaError::Io(err) => write!(f, "I/O error while reading FASTA: {}", err),
+            FastaError::MissingHeader { line } =>
+                write!(f, "line {}: expected a '>' header line", line),
+            FastaError::EmptySequence { id, line } =>
+                write!(f, "line {}: record '{}' has no sequence", line, id),
+            FastaError::InvalidCharacter { line, col, ch } =>
+                write!(f, "line {}, column {}: invalid sequence character {:?}", line, col, ch),
+            FastaError::DuplicateId { id, line } =>
+                write!(f, "line {}: duplicate record id '{}'", line, id),
+        }
+    }
+}
+
+impl error::Error for FastaError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            FastaError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FastaError {
+    fn from(err: io::Error) -> Self {
+        FastaError::Io(err)
+    }
+}
+
+impl From<FastaError> for io::Error {
+    fn from(err: FastaError) -> Self {
+        match err {
+            FastaError::Io(err) => err,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
+
+/// Why a FASTQ file could not be read. Line numbers start at 1.
+#[derive(Debug)]
+pub enum FastqError {
+    Io(io::Error),
+    /// Something other than an `@` header was found at the start of a record.
+    MissingHeader { line: usize },
+    /// The line after the sequence didn't start with `+`.
+    MissingSeparator { line: usize },
+    /// The file ended part way through a record.
+    Truncated { id: String },
+    /// The quality string isn't the same length as the sequence.
+    QualityLength { id: String, line: usize },
+}
+
+impl fmt::Display for FastqError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FastqError::Io(err) => write!(f, "I/O error while reading FASTQ: {}", err),
+            FastqError::MissingHeader { line } =>
+                write!(f, "line {}: expected a '@' header line", line),
+            FastqError::MissingSeparator { line } =>
+                write!(f, "line {}: expected a '+' separator line", line),
+            FastqError::Truncated { id } =>
+                write!(f, "record '{}' is incomplete", id),
+            FastqError::QualityLength { id, line } =>
+                write!(f, "line {}: quality and sequence lengths differ for record '{}'", line, id),
+        }
+    }
+}
+
+impl error::Error for FastqError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            FastqError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FastqError {
+    fn from(err: io::Error) -> Self {
+        FastqError::Io(err)
+    }
+}
+
+impl From<FastqError> for io::Error {
+    fn from(err: FastqError) -> Self {
+        match err {
+            FastqError::Io(err) => err,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
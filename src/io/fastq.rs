@@ -0,0 +1,141 @@
+use std::io;
+use std::io::BufRead;
+use io::{FastqError, Record};
+
+/// A FASTQ record: the sequence as a `Record` plus its Phred quality string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FastqRecord {
+    record: Record,
+    qual: String,
+}
+
+impl FastqRecord {
+    pub fn new() -> Self {
+        FastqRecord::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.record.is_empty()
+    }
+
+    pub fn record(&self) -> &Record {
+        &self.record
+    }
+
+    pub fn qual(&self) -> &String {
+        &self.qual
+    }
+
+    /// Phred scores, assuming the usual offset of 33.
+    pub fn phred_scores(&self) -> impl Iterator<Item = u8> + '_ {
+        self.qual.bytes().map(|b| b.saturating_sub(b'!'))
+    }
+}
+
+/// A reader for four-line FASTQ: `@id`, sequence, `+`, quality. Blank lines
+/// between records are skipped.
+pub struct FastqReader<R: io::Read> {
+    reader: io::BufReader<R>,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: io::Read> FastqReader<R> {
+    pub fn new(reader: R) -> Self {
+        FastqReader {
+            reader: io::BufReader::new(reader),
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    /// Read the next line into `self.line` without its line ending, returning false at EOF.
+    fn next_line(&mut self) -> io::Result<bool> {
+        self.line.clear();
+        let read = self.reader.read_line(&mut self.line)?;
+        if read > 0 {
+            self.line_number += 1;
+        }
+        let trimmed = self.line.trim_end_matches(['\n', '\r']).len();
+        self.line.truncate(trimmed);
+        Ok(read > 0)
+    }
+
+    pub fn read(&mut self, fastq: &mut FastqRecord) -> Result<(), FastqError> {
+        fastq.record.clear();
+        fastq.qual.clear();
+
+        loop {
+            if !self.next_line()? {
+                return Ok(());
+            }
+            if !self.line.trim().is_empty() {
+                break;
+            }
+        }
+        if !self.line.starts_with('@') {
+            return Err(FastqError::MissingHeader { line: self.line_number });
+        }
+        fastq.record.id = self.line[1..].split(' ').next().unwrap_or_default().to_owned();
+
+        let truncated = |fastq: &FastqRecord| FastqError::Truncated { id: fastq.record.id.clone() };
+        if !self.next_line()? {
+            return Err(truncated(fastq));
+        }
+        fastq.record.seq.push_str(self.line.trim_end());
+
+        if !self.next_line()? {
+            return Err(truncated(fastq));
+        }
+        if !self.line.starts_with('+') {
+            return Err(FastqError::MissingSeparator { line: self.line_number });
+        }
+
+        if !self.next_line()? {
+            return Err(truncated(fastq));
+        }
+        fastq.qual.push_str(&self.line);
+        if fastq.qual.len() != fastq.record.seq.len() {
+            return Err(FastqError::QualityLength { id: fastq.record.id.clone(), line: self.line_number });
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: io::Read> Iterator for FastqReader<R> {
+    type Item = Result<FastqRecord, FastqError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = FastqRecord::new();
+        match self.read(&mut record) {
+            Err(err) => Some(Err(err)),
+            Ok(()) if record.is_empty() => None,
+            Ok(()) => Some(Ok(record)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_records() {
+        let fastq = b"@read1 lane 1\nACGTN\n+\nII#5!\n\n@read2\nGG\n+read2\n??\n";
+        let records: Vec<_> = FastqReader::new(&fastq[..]).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].record().id(), "read1");
+        assert_eq!(records[0].record().seq(), "ACGTN");
+        assert_eq!(records[0].phred_scores().collect::<Vec<_>>(), vec![40, 40, 2, 20, 0]);
+        assert_eq!(records[1].qual(), "??");
+    }
+
+    #[test]
+    fn malformed_records() {
+        let err = |input: &[u8]| FastqReader::new(input).find_map(Result::err).unwrap().to_string();
+        assert_eq!(err(b">read1\nACGT\n"), "line 1: expected a '@' header line");
+        assert_eq!(err(b"@read1\nACGT\nIIII\n"), "line 3: expected a '+' separator line");
+        assert_eq!(err(b"@read1\nACGT\n+\nIII\n"), "line 4: quality and sequence lengths differ for record 'read1'");
+        assert_eq!(err(b"@read1\nACGT\n"), "record 'read1' is incomplete");
+    }
+}
@@ -1,11 +1,29 @@
 use std::io::BufRead;
 use std::io;
+use std::collections::HashSet;
 
+mod convert;
+mod error;
+mod fastq;
+mod ref_reader;
+
+pub use self::convert::{Conversion, InvalidCharacters, InvalidResidue, SoftMasking, Typed};
+pub use self::error::{FastaError, FastqError};
+pub use self::fastq::{FastqReader, FastqRecord};
+pub use self::ref_reader::{RefReader, RefRecord, SeqLines};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Record {
     id: String,
     seq: String,
 }
 
+impl Default for Record {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Record {
     pub fn new() -> Self {
         Record {
@@ -23,10 +41,11 @@ impl Record {
         self.seq.clear();
     }
 
+    /// Count of `C` and `G` bases, including soft-masked (lowercase) ones.
     pub fn gc_count(&self) -> usize {
         self.seq
             .chars()
-            .filter(|base| *base == 'C' || *base == 'G')
+            .filter(|base| matches!(base, 'C' | 'G' | 'c' | 'g'))
             .count()
     }
 
@@ -37,12 +56,24 @@ impl Record {
     pub fn id(&self) -> &String {
         &self.id
     }
+
+    pub fn seq(&self) -> &String {
+        &self.seq
+    }
 }
 
 /// A FASTA reader.
+///
+/// By default the reader is forgiving: anything before the first header is
+/// skipped and sequence lines are taken as they are. A strict reader instead
+/// rejects leading junk, empty records, duplicate ids and characters that
+/// can't appear in a sequence, reporting the line and column at fault.
 pub struct FastaReader<R: io::Read> {
     reader: io::BufReader<R>,
     line: String,
+    line_number: usize,
+    strict: bool,
+    seen_ids: HashSet<String>,
 }
 
 impl<R: io::Read> FastaReader<R> {
@@ -50,57 +81,86 @@ impl<R: io::Read> FastaReader<R> {
         FastaReader {
             reader: io::BufReader::new(reader),
             line: Default::default(),
+            line_number: 0,
+            strict: false,
+            seen_ids: HashSet::new(),
+        }
+    }
+
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Read the next line into `self.line`, returning false at EOF.
+    fn next_line(&mut self) -> io::Result<bool> {
+        self.line.clear();
+        let read = self.reader.read_line(&mut self.line)?;
+        if read > 0 {
+            self.line_number += 1;
         }
+        Ok(read > 0)
     }
 
-    pub fn read(&mut self, record: &mut Record) -> io::Result<()> {
+    pub fn read(&mut self, record: &mut Record) -> Result<(), FastaError> {
         record.clear();
 
-        if self.line.is_empty() {
-            self.reader.read_line(&mut self.line)?;
-            if self.line.is_empty() {
-                return Ok(());
-            }
+        if self.line.is_empty() && !self.next_line()? {
+            return Ok(());
         }
 
-        // If the line isn't a header...
-        if !self.line.starts_with('>') {
-            // Loop over the lines until we find something interesting.
-            loop {
-                self.line.clear();
-                self.reader.read_line(&mut self.line)?;
-                if self.line.starts_with('>') {
-                    break;
-                } else if self.line.is_empty() {
-                    return Err(io::Error::new(io::ErrorKind::InvalidInput,
-                                              "Could not find a valid fasta entry in the file"))
+        // Skip forward to the next header, unless we're being strict about it.
+        let mut junk_line = None;
+        while !self.line.starts_with('>') {
+            if !self.line.trim().is_empty() {
+                if self.strict {
+                    return Err(FastaError::MissingHeader { line: self.line_number });
                 }
+                junk_line = junk_line.or(Some(self.line_number));
+            }
+            if !self.next_line()? {
+                return junk_line.map_or(Ok(()), |line| Err(FastaError::MissingHeader { line }));
             }
-            return Err(io::Error::new(io::ErrorKind::Other, "Expected '>' at record start"));
         }
 
+        let header_line = self.line_number;
         record.id = self.line[1..]
             .trim_end()
-            .splitn(2, ' ')
-            .nth(0)
+            .split(' ')
+            .next()
             .map(|s| s.to_owned())
             .unwrap();
 
-        loop {
-            self.line.clear();
-            self.reader.read_line(&mut self.line)?;
-            if self.line.is_empty() || self.line.starts_with('>') {
-                break;
+        if self.strict && !self.seen_ids.insert(record.id.clone()) {
+            return Err(FastaError::DuplicateId { id: record.id.clone(), line: header_line });
+        }
+
+        while self.next_line()? && !self.line.starts_with('>') {
+            let line = self.line.trim_end();
+            if self.strict {
+                if let Some((idx, ch)) = line.char_indices().find(|&(_, c)| !is_sequence_char(c)) {
+                    let col = line[..idx].chars().count() + 1;
+                    return Err(FastaError::InvalidCharacter { line: self.line_number, col, ch });
+                }
             }
-            record.seq.push_str(self.line.trim_end());
+            record.seq.push_str(line);
+        }
+
+        if self.strict && record.seq.is_empty() {
+            return Err(FastaError::EmptySequence { id: record.id.clone(), line: header_line });
         }
 
         Ok(())
     }
 }
 
+/// Residue letters, gaps and the stop symbol.
+fn is_sequence_char(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '-' || c == '.' || c == '*'
+}
+
 impl<T: io::Read> Iterator for FastaReader<T> {
-    type Item = io::Result<Record>;
+    type Item = Result<Record, FastaError>;
     fn next(&mut self) -> Option<<Self as Iterator>::Item> {
         let mut record = Record::new();
         match self.read(&mut record) {
@@ -119,7 +179,7 @@ impl<T: io::Read> Iterator for FastaReader<T> {
 mod tests {
     use super::*;
 
-    const BASIC_FASTA_FORMAT: &'static [u8] = b">id desc
+    const BASIC_FASTA_FORMAT: &[u8] = b">id desc
 ACCGTAGGCTGA
 CCGTAGGCTGAA
 CGTAGGCTGAAA
@@ -132,9 +192,9 @@ ATTGTTGTTTTA
 GGGG
 ";
 
-    const EMPTY_FASTA_FILE: &'static [u8] = b"";
+    const EMPTY_FASTA_FILE: &[u8] = b"";
 
-    const ERRONEOUS_FASTA_FORMAT_01: &'static [u8] = b"
+    const ERRONEOUS_FASTA_FORMAT_01: &[u8] = b"
 these lines at the beginning
 are just junk that should
 be ignored
@@ -167,6 +227,35 @@ GGGG
         assert_eq!(2, record_count);
     }
 
+    #[test]
+    fn strict_rejects_junk() {
+        let mut reader = FastaReader::new(ERRONEOUS_FASTA_FORMAT_01).strict(true);
+        match reader.next() {
+            Some(Err(FastaError::MissingHeader { line })) => assert_eq!(line, 2),
+            other => panic!("Expected a missing header error, got {:?}", other.map(|r| r.map(|_| ()))),
+        }
+    }
+
+    #[test]
+    fn strict_errors_are_located() {
+        let errors: Vec<String> = [&b">a\nAC GT\n"[..], b">a\n>b\nACGT\n", b">a\nAC\n>a\nGT\n"]
+            .iter()
+            .map(|input| FastaReader::new(*input).strict(true).find_map(Result::err).unwrap().to_string())
+            .collect();
+        assert_eq!(errors, vec![
+            "line 2, column 3: invalid sequence character ' '",
+            "line 1: record 'a' has no sequence",
+            "line 3: duplicate record id 'a'",
+        ]);
+    }
+
+    #[test]
+    fn soft_masked_gc_counting() {
+        let record = FastaReader::new(&b">masked\nACgtaCGc\n"[..]).next().unwrap().unwrap();
+        assert_eq!(record.gc_count(), 5);
+        assert_eq!(record.gc_percent(), 0.625);
+    }
+
     #[test]
     fn test_gc_counting() {
         let a: Vec<usize> = FastaReader::new(BASIC_FASTA_FORMAT)
@@ -0,0 +1,372 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io;
+use std::mem;
+use io::{FastaError, Record};
+
+const INITIAL_CAPACITY: usize = 64 * 1024;
+
+/// A FASTA record borrowed from a `RefReader`'s buffer.
+///
+/// Sequence lines are left exactly as they are in the file; use `seq_lines`
+/// to walk them without copying, or `full_seq` to get them joined together.
+#[derive(Debug, Clone, Copy)]
+pub struct RefRecord<'a> {
+    head: &'a [u8],
+    seq: &'a [u8],
+}
+
+impl<'a> RefRecord<'a> {
+    /// The whole header line, without the leading `>`.
+    pub fn head(&self) -> &'a [u8] {
+        self.head
+    }
+
+    pub fn id(&self) -> &'a [u8] {
+        match find_byte(b' ', self.head) {
+            Some(idx) => &self.head[..idx],
+            None => self.head,
+        }
+    }
+
+    pub fn desc(&self) -> Option<&'a [u8]> {
+        find_byte(b' ', self.head).map(|idx| &self.head[idx + 1..])
+    }
+
+    pub fn seq_lines(&self) -> SeqLines<'a> {
+        SeqLines { remaining: self.seq }
+    }
+
+    /// The sequence with line breaks removed. Only allocates if the sequence spans several lines.
+    pub fn full_seq(&self) -> Cow<'a, [u8]> {
+        let mut lines = self.seq_lines();
+        match (lines.next(), lines.next()) {
+            (None, _) => Cow::Borrowed(&[]),
+            (Some(line), None) => Cow::Borrowed(line),
+            _ => Cow::Owned(self.seq_lines().flat_map(|line| line.iter().cloned()).collect()),
+        }
+    }
+
+    pub fn seq_len(&self) -> usize {
+        self.seq_lines().map(|line| line.len()).sum()
+    }
+
+    /// Count of `C` and `G` bases, including soft-masked (lowercase) ones.
+    pub fn gc_count(&self) -> usize {
+        self.seq_lines()
+            .map(|line| line.iter().filter(|&&base| base | 0x20 == b'c' || base | 0x20 == b'g').count())
+            .sum()
+    }
+
+    pub fn gc_percent(&self) -> f64 {
+        self.gc_count() as f64 / self.seq_len() as f64
+    }
+
+    /// Copy into an owned `Record`.
+    pub fn to_owned_record(&self) -> Record {
+        let mut record = Record::new();
+        record.id = String::from_utf8_lossy(self.id()).into_owned();
+        for line in self.seq_lines() {
+            record.seq.push_str(&String::from_utf8_lossy(line));
+        }
+        record
+    }
+}
+
+/// Sequence lines of a `RefRecord`, with line endings (`\n` or `\r\n`) stripped.
+pub struct SeqLines<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Iterator for SeqLines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining.is_empty() {
+                return None;
+            }
+            let (line, rest) = match find_byte(b'\n', self.remaining) {
+                Some(idx) => (&self.remaining[..idx], &self.remaining[idx + 1..]),
+                None => (self.remaining, &[][..]),
+            };
+            self.remaining = rest;
+            let line = trim_cr(line);
+            if !line.is_empty() {
+                return Some(line);
+            }
+        }
+    }
+}
+
+/// A FASTA reader that avoids per-line allocation and UTF-8 validation.
+///
+/// Records are found with a fast newline search over a reusable byte buffer
+/// and handed out as `RefRecord`s that borrow from it, so each record must be
+/// dropped before the next one is read. Like `FastaReader`, it can be made
+/// strict to reject malformed input.
+pub struct RefReader<R: io::Read> {
+    reader: R,
+    buf: Vec<u8>,
+    /// Start of the data that hasn't been handed out yet.
+    start: usize,
+    /// End of the valid data in `buf`.
+    end: usize,
+    eof: bool,
+    /// Lines before `start`. Only tracked in strict mode or before the first record.
+    line_number: usize,
+    strict: bool,
+    seen_ids: HashSet<Vec<u8>>,
+}
+
+impl<R: io::Read> RefReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_capacity(INITIAL_CAPACITY, reader)
+    }
+
+    pub fn with_capacity(capacity: usize, reader: R) -> Self {
+        RefReader {
+            reader,
+            buf: vec![0; capacity.max(1)],
+            start: 0,
+            end: 0,
+            eof: false,
+            line_number: 0,
+            strict: false,
+            seen_ids: HashSet::new(),
+        }
+    }
+
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Move unread data to the front of the buffer, growing it if it is already full,
+    /// then read more. Returns false once the underlying reader is exhausted.
+    fn fill(&mut self) -> io::Result<bool> {
+        if self.eof {
+            return Ok(false);
+        }
+        if self.start > 0 {
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+        if self.end == self.buf.len() {
+            let len = self.buf.len();
+            self.buf.resize(len * 2, 0);
+        }
+        loop {
+            match self.reader.read(&mut self.buf[self.end..]) {
+                Ok(0) => {
+                    self.eof = true;
+                    return Ok(false);
+                }
+                Ok(n) => {
+                    self.end += n;
+                    return Ok(true);
+                }
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Advance `start` to the next '>' that begins a line.
+    fn skip_to_header(&mut self) -> Result<bool, FastaError> {
+        let mut junk_line = None;
+        loop {
+            if self.start == self.end && !self.fill()? {
+                return junk_line.map_or(Ok(false), |line| Err(FastaError::MissingHeader { line }));
+            }
+            if self.buf[self.start] == b'>' {
+                return Ok(true);
+            }
+            let line_end = loop {
+                match find_byte(b'\n', &self.buf[self.start..self.end]) {
+                    Some(idx) => break Some(self.start + idx),
+                    None if self.fill()? => continue,
+                    None => break None,
+                }
+            };
+            let line = &self.buf[self.start..line_end.unwrap_or(self.end)];
+            self.line_number += 1;
+            if !line.iter().all(u8::is_ascii_whitespace) {
+                if self.strict {
+                    return Err(FastaError::MissingHeader { line: self.line_number });
+                }
+                junk_line = junk_line.or(Some(self.line_number));
+            }
+            self.start = line_end.map_or(self.end, |idx| idx + 1);
+        }
+    }
+
+    /// Read the next record, or `None` at the end of the input.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Result<RefRecord<'_>, FastaError>> {
+        match self.skip_to_header() {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(err) => return Some(Err(err)),
+        }
+
+        // The record runs up to the next "\n>" or the end of the input.
+        let mut searched = self.start;
+        let record_end = loop {
+            match find_newline_header(&self.buf[searched..self.end]) {
+                Some(idx) => break searched + idx + 1,
+                None => {
+                    // Keep the last byte: it may be the '\n' of a "\n>" split across reads.
+                    let offset = searched.max(self.end.saturating_sub(1)) - self.start;
+                    match self.fill() {
+                        Ok(true) => searched = self.start + offset,
+                        Ok(false) => break self.end,
+                        Err(err) => return Some(Err(err.into())),
+                    }
+                }
+            }
+        };
+
+        let record_start = mem::replace(&mut self.start, record_end);
+        let header_line = self.line_number + 1;
+        let data = &self.buf[record_start..record_end];
+        let head_end = find_byte(b'\n', data).unwrap_or(data.len());
+        let record = RefRecord {
+            head: trim_cr(&data[1..head_end]),
+            seq: &data[(head_end + 1).min(data.len())..],
+        };
+
+        if self.strict {
+            if let Err(err) = validate(&record, header_line, &mut self.seen_ids) {
+                return Some(Err(err));
+            }
+            self.line_number += data.iter().filter(|&&b| b == b'\n').count();
+        }
+        Some(Ok(record))
+    }
+}
+
+fn validate(record: &RefRecord, header_line: usize, seen_ids: &mut HashSet<Vec<u8>>) -> Result<(), FastaError> {
+    let id = || String::from_utf8_lossy(record.id()).into_owned();
+    if !seen_ids.insert(record.id().to_vec()) {
+        return Err(FastaError::DuplicateId { id: id(), line: header_line });
+    }
+    let mut empty = true;
+    for (offset, line) in record.seq.split(|&b| b == b'\n').enumerate() {
+        let line = trim_cr(line);
+        if let Some(col) = line.iter().position(|&b| !super::is_sequence_char(b as char)) {
+            let ch = String::from_utf8_lossy(&line[col..]).chars().next().unwrap_or('\u{fffd}');
+            let col = String::from_utf8_lossy(&line[..col]).chars().count() + 1;
+            return Err(FastaError::InvalidCharacter { line: header_line + offset + 1, col, ch });
+        }
+        empty &= line.is_empty();
+    }
+    if empty {
+        return Err(FastaError::EmptySequence { id: id(), line: header_line });
+    }
+    Ok(())
+}
+
+fn trim_cr(line: &[u8]) -> &[u8] {
+    match line.last() {
+        Some(b'\r') => &line[..line.len() - 1],
+        _ => line,
+    }
+}
+
+/// Position of the '\n' in the first "\n>" of `haystack`.
+fn find_newline_header(haystack: &[u8]) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(idx) = find_byte(b'\n', &haystack[offset..]) {
+        let pos = offset + idx;
+        if haystack.get(pos + 1) == Some(&b'>') {
+            return Some(pos);
+        }
+        offset = pos + 1;
+    }
+    None
+}
+
+const LO: u64 = 0x0101_0101_0101_0101;
+const HI: u64 = 0x8080_8080_8080_8080;
+
+/// Find the first occurrence of `needle`, eight bytes at a time.
+pub fn find_byte(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LO * u64::from(needle);
+    let mut chunks = haystack.chunks_exact(8);
+    let mut offset = 0;
+    for chunk in chunks.by_ref() {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(chunk);
+        let x = u64::from_le_bytes(word) ^ repeated;
+        // Sets the high bit of every byte of `x` that is zero.
+        let found = x.wrapping_sub(LO) & !x & HI;
+        if found != 0 {
+            return Some(offset + (found.trailing_zeros() / 8) as usize);
+        }
+        offset += 8;
+    }
+    chunks.remainder().iter().position(|&b| b == needle).map(|idx| offset + idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FASTA: &[u8] = b"junk\n>id1 some description\r\nACGT\r\nGGCC\n\n>id2\nTTTT\n>id3\n";
+
+    #[test]
+    fn borrowed_records() {
+        // A tiny buffer forces records to straddle refills.
+        let mut reader = RefReader::with_capacity(3, FASTA);
+        let mut seen = Vec::new();
+        while let Some(record) = reader.next() {
+            let record = record.unwrap();
+            seen.push((record.id().to_vec(), record.desc().map(<[u8]>::to_vec), record.full_seq().into_owned()));
+        }
+        assert_eq!(seen, vec![
+            (b"id1".to_vec(), Some(b"some description".to_vec()), b"ACGTGGCC".to_vec()),
+            (b"id2".to_vec(), None, b"TTTT".to_vec()),
+            (b"id3".to_vec(), None, Vec::new()),
+        ]);
+    }
+
+    #[test]
+    fn strict_mode() {
+        let mut reader = RefReader::new(FASTA).strict(true);
+        match reader.next() {
+            Some(Err(FastaError::MissingHeader { line: 1 })) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+        let mut reader = RefReader::new(&b">a\nAC\nG?T\n"[..]).strict(true);
+        match reader.next() {
+            Some(Err(FastaError::InvalidCharacter { line: 3, col: 2, ch: '?' })) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn matches_fasta_reader() {
+        let input = b">a\nACGTTGCA\nCCGG\n>b\nGGGGAAAAT\n";
+        let mut reader = RefReader::new(&input[..]);
+        let mut gc = Vec::new();
+        while let Some(record) = reader.next() {
+            gc.push(record.unwrap().gc_count());
+        }
+        let expected: Vec<usize> = ::io::FastaReader::new(&input[..])
+            .map(|record| record.unwrap().gc_count())
+            .collect();
+        assert_eq!(gc, expected);
+    }
+
+    #[test]
+    fn byte_search() {
+        let haystack = b"abcdefghijklmnopqrstuvwxyz";
+        for (idx, &b) in haystack.iter().enumerate() {
+            assert_eq!(find_byte(b, haystack), Some(idx));
+        }
+        assert_eq!(find_byte(b'!', haystack), None);
+        assert_eq!(find_byte(0x80, &[0x7f, 0x00, 0x80][..]), Some(2));
+    }
+}
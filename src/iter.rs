@@ -0,0 +1,99 @@
+//! Iterator adaptors for comparing items two at a time.
+
+/// Consecutive, non-overlapping pairs of items: `a, b, c, d` becomes
+/// `(a, b), (c, d)`. An odd item left at the end is kept for `remainder`.
+#[derive(Debug)]
+pub struct Pairer<I: Iterator> {
+    it: I,
+    leftover: Option<I::Item>,
+}
+
+impl<I: Iterator> Pairer<I> {
+    pub fn new(it: I) -> Self {
+        Pairer { it, leftover: None }
+    }
+
+    /// The unpaired last item of an odd-length iterator, once it has run out.
+    pub fn remainder(&mut self) -> Option<I::Item> {
+        self.leftover.take()
+    }
+}
+
+impl<I: Iterator> Iterator for Pairer<I> {
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.it.next()?;
+        match self.it.next() {
+            Some(second) => Some((first, second)),
+            None => {
+                self.leftover = Some(first);
+                None
+            }
+        }
+    }
+}
+
+/// Adds `tuples` to every iterator.
+pub trait Tuples: Iterator + Sized {
+    /// Group items into consecutive pairs; see `Pairer`.
+    fn tuples(self) -> Pairer<Self> {
+        Pairer::new(self)
+    }
+}
+
+impl<I: Iterator> Tuples for I {}
+
+/// Every unordered pair of distinct items, in order: `(0, 1), (0, 2), ...
+/// (1, 2), ...`, each with the items' indices.
+#[derive(Debug, Clone)]
+pub struct AllPairs<'a, T: 'a> {
+    items: &'a [T],
+    i: usize,
+    j: usize,
+}
+
+pub fn all_pairs<T>(items: &[T]) -> AllPairs<'_, T> {
+    AllPairs { items, i: 0, j: 1 }
+}
+
+impl<'a, T> Iterator for AllPairs<'a, T> {
+    type Item = ((usize, &'a T), (usize, &'a T));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.j >= self.items.len() {
+            self.i += 1;
+            self.j = self.i + 1;
+            if self.j >= self.items.len() {
+                return None;
+            }
+        }
+        let pair = ((self.i, &self.items[self.i]), (self.j, &self.items[self.j]));
+        self.j += 1;
+        Some(pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_pairs() {
+        let mut pairs = (1..6).tuples();
+        assert_eq!(pairs.by_ref().collect::<Vec<_>>(), vec![(1, 2), (3, 4)]);
+        assert_eq!(pairs.remainder(), Some(5));
+        let mut even = "ab".chars().tuples();
+        assert_eq!(even.next(), Some(('a', 'b')));
+        assert_eq!(even.next(), None);
+        assert_eq!(even.remainder(), None);
+    }
+
+    #[test]
+    fn every_pair() {
+        let pairs: Vec<(usize, usize)> = all_pairs(&["a", "b", "c"]).map(|((i, _), (j, _))| (i, j)).collect();
+        assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+        assert_eq!(all_pairs(&[1]).count(), 0);
+        assert_eq!(all_pairs::<u8>(&[]).count(), 0);
+    }
+}
@@ -1,2 +1,21 @@
+extern crate bit_set;
+
 pub mod seq;
-pub mod io;
\ No newline at end of file
+pub mod io;
+pub mod iter;
+pub mod msa;
+pub mod tree;
+pub mod align;
+pub mod check;
+pub mod dataset;
+pub mod distance;
+pub mod error;
+pub mod mendel;
+pub mod num;
+pub mod popgen;
+pub mod population;
+pub mod problem;
+pub mod problems;
+pub mod report;
+pub mod simulate;
+pub mod stats;
@@ -1,16 +1,21 @@
-#[macro_use]
 extern crate clap;
+extern crate rustalind;
 
-mod dna;
-mod rna;
-mod revc;
-mod gc;
-mod fib;
-mod hamm;
-mod iprb;
-mod prot;
+use std::fs::File;
+use std::io::{self, Write};
+use std::process;
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use rustalind::error::{self, Error};
+use rustalind::problem::{ArgKind, ArgSpec, Args, Solver, STDIN};
+use rustalind::check;
+use rustalind::dataset;
+use rustalind::problems;
+use rustalind::report::{OutputFormat, OUTPUT_FORMAT_NAMES};
 
 fn file_exists(path: String) -> Result<(), String> {
+    if path == STDIN {
+        return Ok(());
+    }
     std::fs::metadata(path)
         .map(|_metadata| ())
         .map_err(|e| e.to_string())
@@ -22,57 +27,193 @@ fn is_valid_integer(input: String) -> Result<(), String> {
         .map_err(|e| e.to_string())
 }
 
+fn is_valid_float(input: String) -> Result<(), String> {
+    input.parse::<f64>()
+        .map(|_x| ())
+        .map_err(|e| e.to_string())
+}
+
+fn clap_arg(spec: &ArgSpec) -> Arg<'static, 'static> {
+    let mut arg = Arg::with_name(spec.name)
+        .help(spec.help)
+        .required(spec.required);
+    arg = match spec.kind {
+        ArgKind::Input => arg.validator(file_exists),
+        ArgKind::Inputs => arg.multiple(true).validator(file_exists),
+        ArgKind::Integer => arg.takes_value(true).value_name("int").validator(is_valid_integer),
+        ArgKind::Float => arg.takes_value(true).value_name("num").validator(is_valid_float),
+        ArgKind::Text => arg.takes_value(true),
+        ArgKind::Flag => arg,
+        ArgKind::Choice(values) => arg.takes_value(true).possible_values(values),
+    };
+    if let Some(short) = spec.short {
+        arg = arg.short(short);
+    }
+    if let Some(long) = spec.long {
+        arg = arg.long(long);
+    }
+    arg
+}
+
+fn subcommand(solver: &dyn Solver) -> App<'static, 'static> {
+    let args: Vec<Arg> = solver.args().iter().map(clap_arg).collect();
+    SubCommand::with_name(solver.id())
+        .about(solver.description())
+        .version("0.0.1")
+        .args(&args)
+}
+
+fn collect_args(solver: &dyn Solver, matches: &ArgMatches) -> Args {
+    solver.args().iter().fold(Args::new(), |args, spec| match spec.kind {
+        ArgKind::Flag if matches.is_present(spec.name) => args.with_flag(spec.name),
+        _ => matches.values_of(spec.name)
+            .into_iter()
+            .flatten()
+            .fold(args, |args, value| args.push_value(spec.name, value)),
+    })
+}
+
+fn write_output(path: Option<&str>, output: &str) -> error::Result<()> {
+    let mut writer: Box<dyn Write> = match path {
+        Some(path) if path != STDIN => Box::new(File::create(path)?),
+        _ => Box::new(io::stdout()),
+    };
+    writeln!(writer, "{}", output)?;
+    Ok(writer.flush()?)
+}
+
+/// Report an error and exit with its status code.
+fn fail(name: &str, err: &Error) -> ! {
+    eprintln!("rustalind {}: {}", name, err);
+    process::exit(err.exit_code())
+}
+
+fn check_command() -> App<'static, 'static> {
+    SubCommand::with_name("check")
+        .about("Check every problem against the sample answers in a test-data directory")
+        .version("0.0.1")
+        .arg(Arg::with_name("DIR")
+            .help("Directory holding one folder of samples per problem")
+            .default_value("test-data")
+            .validator(file_exists))
+}
+
+/// Run every case under `dir`, returning the results table and whether they all passed.
+fn run_checks(dir: &str) -> error::Result<(String, bool)> {
+    let results: Vec<_> = check::discover(dir)?
+        .into_iter()
+        .map(|case| {
+            let outcome = check::run(&case);
+            (case, outcome)
+        })
+        .collect();
+    let all_passed = results.iter().all(|(_, outcome)| outcome.passed());
+    Ok((check::format_table(&results), all_passed))
+}
+
+fn solve_command() -> App<'static, 'static> {
+    SubCommand::with_name("solve")
+        .about("Solve downloaded rosalind_<id>.txt datasets, writing each answer to <id>.out")
+        .version("0.0.1")
+        .arg(Arg::with_name("PATHS")
+            .help("Dataset files, or directories to search for them")
+            .required(true)
+            .multiple(true)
+            .validator(file_exists))
+}
+
+/// Solve every dataset in `paths`, returning a line per dataset and whether they all succeeded.
+fn run_datasets(paths: &[&str]) -> error::Result<(String, bool)> {
+    let mut report = Vec::new();
+    let mut all_solved = true;
+    for path in dataset::find_datasets(paths)? {
+        match dataset::solve(&path) {
+            Ok(out) => report.push(format!("{} -> {}", path.display(), out.display())),
+            Err(err) => {
+                eprintln!("rustalind solve: {}: {}", path.display(), err);
+                all_solved = false;
+            }
+        }
+    }
+    Ok((report.join("\n"), all_solved))
+}
+
 fn main() {
-    let app = clap_app!(rustalind =>
-        (version: "0.0.1")
-        (author: "Rob Syme <rob.syme@gmail.com>")
-        (about: "Learning Rust by implementing solutions to Rosalind problems.")
-        (@subcommand dna =>
-            (about: "Count nucleotide occurrence")
-            (version: "0.0.1")
-            (@arg INPUT: +required {file_exists} "Input file to read"))
-        (@subcommand rna =>
-            (about: "Translate RNA to DNA")
-            (version: "0.0.1")
-            (@arg INPUT: +required {file_exists} "Input file to read"))
-        (@subcommand revc =>
-            (about: "Count nucleotide occurrence")
-            (version: "0.0.1")
-            (@arg INPUT: +required {file_exists} "Input file to read"))
-        (@subcommand fib =>
-            (about: "Population size simulation")
-            (version: "0.0.1")
-            (@arg generations: -g --generations <int> +required {is_valid_integer} "Number of rabbit generations to simulate")
-            (@arg fecundidty: -f --fecundity <int> +required {is_valid_integer} "Number of rabbit pairs are produced per rabbit pair per generation"))
-        (@subcommand gc =>
-            (about: "Identify highest GC content sequence from fasta file")
-            (version: "0.0.1")
-            (@arg INPUT: +required {file_exists} "Input file to read"))
-        (@subcommand hamm =>
-            (about: "Calculate hamming distance between sequences")
-            (version: "0.0.1")
-            (@arg INPUT: +required {file_exists} "Input file to read"))
-        (@subcommand iprb =>
-            (about: "Intro to Mendelian Inheritance")
-            (version: "0.0.1")
-            (@arg dominant: -k <int> +required {is_valid_integer} "Number of homozygous dominant individuals")
-            (@arg heterozygous: -m <int> +required {is_valid_integer} "Number of heterozygous individuals")
-            (@arg recessive: -n <int> +required {is_valid_integer} "Number of homozygous recessive individuals"))
-        (@subcommand prot =>
-            (about: "Translate RNA into protein")
-            (version: "0.0.1")
-            (@arg INPUT: +required {file_exists} "Input file to read"))
-        );
-
-    match app.get_matches().subcommand() {
-        ("dna", Some(args)) => dna::run(args),
-        ("rna", Some(args)) => rna::run(args),
-        ("revc", Some(args)) => revc::run(args),
-        ("fib", Some(args)) => fib::run(args),
-        ("gc", Some(args)) => gc::run(args),
-        ("hamm", Some(args)) => hamm::run(args),
-        ("iprb", Some(args)) => iprb::run(args),
-        ("prot", Some(args)) => prot::run(args),
-        _ => {},
+    let registry = problems::registry();
+
+    let app = App::new("rustalind")
+        .version("0.0.1")
+        .author("Rob Syme <rob.syme@gmail.com>")
+        .about("Learning Rust by implementing solutions to Rosalind problems.")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(Arg::with_name("output")
+            .short("o")
+            .long("output")
+            .takes_value(true)
+            .value_name("FILE")
+            .global(true)
+            .help("Write the answer to FILE instead of standard output"))
+        .arg(Arg::with_name("format")
+            .long("format")
+            .takes_value(true)
+            .possible_values(OUTPUT_FORMAT_NAMES)
+            .default_value("text")
+            .global(true)
+            .help("Print answers as Rosalind expects them, or as JSON or TSV records"))
+        .subcommands(registry.iter().map(|solver| subcommand(solver.as_ref())))
+        .subcommand(check_command())
+        .subcommand(solve_command());
+
+    let matches = match app.get_matches_safe() {
+        Ok(matches) => matches,
+        // Help and version requests aren't errors; clap prints them and exits cleanly.
+        Err(err) if !err.use_stderr() => err.exit(),
+        Err(err) => {
+            let err = Error::usage(err.message);
+            eprintln!("{}", err);
+            process::exit(err.exit_code())
+        }
+    };
+    let (name, sub_matches) = matches.subcommand();
+    let output_path = sub_matches.and_then(|m| m.value_of("output"));
+
+    let batch = match name {
+        "check" => {
+            let dir = sub_matches.and_then(|m| m.value_of("DIR")).expect("DIR has a default");
+            Some(run_checks(dir))
+        }
+        "solve" => {
+            let paths: Vec<&str> = sub_matches.and_then(|m| m.values_of("PATHS")).expect("PATHS is required").collect();
+            Some(run_datasets(&paths))
+        }
+        _ => None,
+    };
+    if let Some(result) = batch {
+        let written = result.and_then(|(report, ok)| {
+            if !report.is_empty() {
+                write_output(output_path, &report)?;
+            }
+            Ok(ok)
+        });
+        match written {
+            Ok(true) => {}
+            Ok(false) => process::exit(1),
+            Err(err) => fail(name, &err),
+        }
+        return;
+    }
+
+    let solver = registry.iter()
+        .find(|solver| solver.id() == name)
+        .expect("clap only accepts registered subcommands");
+    let args = collect_args(solver.as_ref(), sub_matches.expect("subcommands always have matches"));
+
+    let format: OutputFormat = sub_matches.and_then(|m| m.value_of("format"))
+        .unwrap_or_default()
+        .parse()
+        .expect("clap only accepts known formats");
+
+    if let Err(err) = solver.run_as(&args, format).and_then(|output| write_output(output_path, &output)) {
+        fail(name, &err);
     }
 }
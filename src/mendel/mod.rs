@@ -0,0 +1,355 @@
+//! Mendelian inheritance at independent loci, each with a dominant and a
+//! recessive allele.
+//!
+//! A `Distribution` gives the chance of each genotype at one locus, and
+//! crossing two of them is a Punnett square. An `Organism` has one
+//! distribution per locus, so crossing organisms crosses each locus in turn.
+
+use std::f64::consts::LN_10;
+use std::fmt;
+use std::str::FromStr;
+use error::{Error, Result};
+use num::{binomial_ln_pmf, binomial_ln_tail, ln_add, ln_choose};
+
+pub mod pedigree;
+
+pub use self::pedigree::pedigree_genotype;
+
+/// The genotype at a single locus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Genotype {
+    /// `AA`
+    HomozygousDominant,
+    /// `Aa`
+    Heterozygous,
+    /// `aa`
+    HomozygousRecessive,
+}
+
+pub const GENOTYPES: [Genotype; 3] = [Genotype::HomozygousDominant, Genotype::Heterozygous, Genotype::HomozygousRecessive];
+
+impl Genotype {
+    fn index(self) -> usize {
+        match self {
+            Genotype::HomozygousDominant => 0,
+            Genotype::Heterozygous => 1,
+            Genotype::HomozygousRecessive => 2,
+        }
+    }
+
+    /// Number of dominant alleles, from 0 to 2.
+    pub fn dominant_alleles(self) -> usize {
+        2 - self.index()
+    }
+
+    pub fn has_dominant_phenotype(self) -> bool {
+        self != Genotype::HomozygousRecessive
+    }
+
+    /// The genotype written with `letter` as the dominant allele, such as `Bb`.
+    pub fn to_letters(self, letter: char) -> String {
+        let (dominant, recessive) = (letter.to_ascii_uppercase(), letter.to_ascii_lowercase());
+        match self {
+            Genotype::HomozygousDominant => format!("{}{}", dominant, dominant),
+            Genotype::Heterozygous => format!("{}{}", dominant, recessive),
+            Genotype::HomozygousRecessive => format!("{}{}", recessive, recessive),
+        }
+    }
+}
+
+/// Parses two alleles of the same letter, upper case being dominant.
+impl FromStr for Genotype {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.as_bytes() {
+            &[a, b] if a.is_ascii_alphabetic() && a.eq_ignore_ascii_case(&b) => {
+                match (a.is_ascii_uppercase(), b.is_ascii_uppercase()) {
+                    (true, true) => Ok(Genotype::HomozygousDominant),
+                    (false, false) => Ok(Genotype::HomozygousRecessive),
+                    _ => Ok(Genotype::Heterozygous),
+                }
+            }
+            _ => Err(Error::parse(format!("Invalid genotype '{}', expected two alleles such as 'Aa'", s))),
+        }
+    }
+}
+
+impl fmt::Display for Genotype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_letters('A'))
+    }
+}
+
+/// The chance of each genotype at one locus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distribution([f64; 3]);
+
+impl Distribution {
+    pub fn certain(genotype: Genotype) -> Self {
+        let mut probabilities = [0.0; 3];
+        probabilities[genotype.index()] = 1.0;
+        Distribution(probabilities)
+    }
+
+    /// Weighted average of distributions. The weights should add up to 1.
+    pub fn mixture<I: IntoIterator<Item = (f64, Distribution)>>(parts: I) -> Self {
+        let mut probabilities = [0.0; 3];
+        for (weight, part) in parts {
+            for (total, p) in probabilities.iter_mut().zip(&part.0) {
+                *total += weight * p;
+            }
+        }
+        Distribution(probabilities)
+    }
+
+    pub fn probability(&self, genotype: Genotype) -> f64 {
+        self.0[genotype.index()]
+    }
+
+    pub fn dominant_phenotype(&self) -> f64 {
+        1.0 - self.probability(Genotype::HomozygousRecessive)
+    }
+
+    /// Chance of passing on the dominant allele.
+    fn dominant_gamete(&self) -> f64 {
+        GENOTYPES.iter().map(|&g| self.probability(g) * g.dominant_alleles() as f64 / 2.0).sum()
+    }
+
+    /// Offspring of one parent from each distribution: a Punnett square,
+    /// each parent passing on either of its alleles with equal chance.
+    pub fn cross(&self, other: &Distribution) -> Distribution {
+        let (p, q) = (self.dominant_gamete(), other.dominant_gamete());
+        Distribution([p * q, p * (1.0 - q) + (1.0 - p) * q, (1.0 - p) * (1.0 - q)])
+    }
+}
+
+impl From<Genotype> for Distribution {
+    fn from(genotype: Genotype) -> Self {
+        Distribution::certain(genotype)
+    }
+}
+
+/// An individual's genotype at any number of independent loci, known or
+/// only known in distribution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Organism {
+    loci: Vec<Distribution>,
+}
+
+impl Organism {
+    pub fn new(loci: Vec<Distribution>) -> Self {
+        Organism { loci }
+    }
+
+    pub fn loci(&self) -> &[Distribution] {
+        &self.loci
+    }
+
+    /// Offspring of `self` and `other`, crossing each locus independently.
+    pub fn cross(&self, other: &Organism) -> Result<Organism> {
+        if self.loci.len() != other.loci.len() {
+            return Err(Error::domain(format!("Can't cross an organism with {} loci with one with {}",
+                                             self.loci.len(), other.loci.len())));
+        }
+        Ok(Organism { loci: self.loci.iter().zip(&other.loci).map(|(a, b)| a.cross(b)).collect() })
+    }
+
+    /// Chance of having exactly these genotypes, one per locus.
+    pub fn probability(&self, genotypes: &[Genotype]) -> f64 {
+        self.query(genotypes, |locus, &genotype| locus.probability(genotype))
+    }
+
+    /// Chance of showing these phenotypes, `true` meaning dominant, one per locus.
+    pub fn phenotype_probability(&self, dominant: &[bool]) -> f64 {
+        self.query(dominant, |locus, &dominant| {
+            if dominant { locus.dominant_phenotype() } else { 1.0 - locus.dominant_phenotype() }
+        })
+    }
+
+    /// Product of per-locus chances, which is the joint chance since loci are independent.
+    fn query<Q, F: Fn(&Distribution, &Q) -> f64>(&self, per_locus: &[Q], f: F) -> f64 {
+        assert_eq!(per_locus.len(), self.loci.len(), "need one query per locus");
+        self.loci.iter().zip(per_locus).map(|(locus, q)| f(locus, q)).product()
+    }
+}
+
+/// Parse genotypes written one locus after another, such as `AaBb`.
+pub fn parse_genotypes(s: &str) -> Result<Vec<Genotype>> {
+    if s.is_empty() || !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return Err(Error::parse(format!("Invalid genotype '{}', expected two alleles per locus such as 'AaBb'", s)));
+    }
+    (0..s.len()).step_by(2).map(|idx| s[idx..idx + 2].parse()).collect()
+}
+
+impl From<&[Genotype]> for Organism {
+    fn from(genotypes: &[Genotype]) -> Self {
+        Organism { loci: genotypes.iter().map(|&g| Distribution::certain(g)).collect() }
+    }
+}
+
+/// Parses genotypes written one locus after another, such as `AaBb`.
+impl FromStr for Organism {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_genotypes(s).map(|genotypes| Organism::from(&genotypes[..]))
+    }
+}
+
+impl From<Genotype> for Organism {
+    fn from(genotype: Genotype) -> Self {
+        Organism { loci: vec![Distribution::certain(genotype)] }
+    }
+}
+
+/// Offspring of two different individuals drawn at random from a population
+/// with `counts[g]` individuals of each genotype, in `GENOTYPES` order.
+pub fn random_mating(counts: [usize; 3]) -> Result<Distribution> {
+    let total = counts.iter().map(|&c| c as u64).sum();
+    if total < 2 {
+        return Err(Error::domain(format!("Need at least two individuals to mate, found {}", total)));
+    }
+    // Chances of drawing each unordered pair of genotypes.
+    let ln_pairs = ln_choose(total, 2);
+    let mut parts = Vec::new();
+    for (i, &a) in GENOTYPES.iter().enumerate() {
+        for (j, &b) in GENOTYPES.iter().enumerate().skip(i) {
+            let (ci, cj) = (counts[i] as u64, counts[j] as u64);
+            let ln_ways = if i == j { ln_choose(ci, 2) } else { (ci as f64).ln() + (cj as f64).ln() };
+            parts.push(((ln_ways - ln_pairs).exp(), Distribution::from(a).cross(&b.into())));
+        }
+    }
+    Ok(Distribution::mixture(parts))
+}
+
+/// Probability that two individuals drawn at random from a population of `k`
+/// homozygous dominant, `m` heterozygous and `n` homozygous recessive
+/// individuals produce offspring showing the dominant phenotype.
+pub fn dominant_phenotype_probability(k: usize, m: usize, n: usize) -> Result<f64> {
+    random_mating([k, m, n]).map(|offspring| offspring.dominant_phenotype())
+}
+
+/// The six kinds of couple, as pairs of parent genotypes, in the order IEV lists them.
+pub const COUPLES: [(Genotype, Genotype); 6] = [
+    (Genotype::HomozygousDominant, Genotype::HomozygousDominant),
+    (Genotype::HomozygousDominant, Genotype::Heterozygous),
+    (Genotype::HomozygousDominant, Genotype::HomozygousRecessive),
+    (Genotype::Heterozygous, Genotype::Heterozygous),
+    (Genotype::Heterozygous, Genotype::HomozygousRecessive),
+    (Genotype::HomozygousRecessive, Genotype::HomozygousRecessive),
+];
+
+/// Expected number of offspring with the dominant phenotype when
+/// `couples[i]` couples of each kind in `COUPLES` have `offspring` children each.
+pub fn expected_dominant_offspring(couples: [usize; 6], offspring: usize) -> f64 {
+    COUPLES.iter().zip(&couples)
+        .map(|(&(a, b), &count)| {
+            let child = Distribution::from(a).cross(&b.into());
+            count as f64 * offspring as f64 * child.dominant_phenotype()
+        })
+        .sum()
+}
+
+/// Most generations `independent_alleles` sums over; the binomial tail has a
+/// term for each of the `2^generations` descendants.
+pub const MAX_GENERATIONS: u32 = 20;
+
+/// Chance that at least `at_least` of the `2^generations` descendants in
+/// the last generation have `genotypes`, when the founder has `genotypes`
+/// and every organism has two children with a mate of `genotypes` (LIA).
+pub fn independent_alleles(genotypes: &[Genotype], generations: u32, at_least: u64) -> Result<f64> {
+    if generations > MAX_GENERATIONS {
+        return Err(Error::domain(format!("{} generations is too many, at most {}", generations, MAX_GENERATIONS)));
+    }
+    let count = 1u64 << generations;
+    let founder = Organism::from(genotypes);
+    let mut descendant = founder.clone();
+    for _ in 0..generations {
+        descendant = descendant.cross(&founder)?;
+    }
+    Ok(binomial_ln_tail(count, at_least, descendant.probability(genotypes)).exp())
+}
+
+/// Most chromosome pairs `shared_chromosomes_log10` answers for; it returns
+/// a value for each of the `2n` chromosomes.
+pub const MAX_CHROMOSOME_PAIRS: u64 = 1_000_000;
+
+/// `log10` of the chance that two siblings share at least `k` of the `2n`
+/// chromosomes of a diploid organism with `n` pairs, for each `k` from 1 to
+/// `2n` (INDC). Chromosomes segregate independently, so each is shared with
+/// chance 1/2. The tails are summed once, from `k = 2n` down.
+pub fn shared_chromosomes_log10(n: u64) -> Result<Vec<f64>> {
+    let chromosomes = n.checked_mul(2)
+        .filter(|_| n <= MAX_CHROMOSOME_PAIRS)
+        .ok_or_else(|| Error::domain(format!("{} chromosome pairs is too many, at most {}", n, MAX_CHROMOSOME_PAIRS)))?;
+    let mut ln_tail = f64::NEG_INFINITY;
+    let mut tails: Vec<f64> = (1..=chromosomes).rev()
+        .map(|k| {
+            ln_tail = ln_add(ln_tail, binomial_ln_pmf(chromosomes, k, 0.5));
+            // Rounding can push a sum of chances just past 1.
+            ln_tail.min(0.0) / LN_10
+        })
+        .collect();
+    tails.reverse();
+    Ok(tails)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_population() {
+        assert!((dominant_phenotype_probability(2, 2, 2).unwrap() - 0.78333).abs() < 1e-5);
+    }
+
+    #[test]
+    fn small_populations() {
+        assert!(dominant_phenotype_probability(0, 0, 0).is_err());
+        assert!(dominant_phenotype_probability(0, 1, 0).is_err());
+        assert_eq!(dominant_phenotype_probability(0, 0, 2).unwrap(), 0.0);
+        assert_eq!(dominant_phenotype_probability(2, 0, 0).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn punnett_squares() {
+        let het = Distribution::certain(Genotype::Heterozygous);
+        assert_eq!(het.cross(&het), Distribution([0.25, 0.5, 0.25]));
+        assert_eq!(het.cross(&het).dominant_phenotype(), 0.75);
+
+        let dihybrid: Organism = "AaBb".parse().unwrap();
+        let offspring = dihybrid.cross(&dihybrid).unwrap();
+        assert_eq!(offspring.phenotype_probability(&[true, true]), 9.0 / 16.0);
+        assert_eq!(offspring.phenotype_probability(&[true, false]), 3.0 / 16.0);
+        assert_eq!(offspring.probability(&[Genotype::Heterozygous, Genotype::Heterozygous]), 0.25);
+        assert!(dihybrid.cross(&Organism::from(Genotype::Heterozygous)).is_err());
+
+        assert_eq!("aA".parse::<Genotype>().unwrap(), Genotype::Heterozygous);
+        assert!("Ab".parse::<Genotype>().is_err());
+        assert!("AaB".parse::<Organism>().is_err());
+        assert_eq!(Genotype::HomozygousRecessive.to_letters('B'), "bb");
+    }
+
+    #[test]
+    fn sample_datasets() {
+        // IEV and LIA.
+        assert_eq!(expected_dominant_offspring([1, 0, 0, 1, 0, 1], 2), 3.5);
+        assert_eq!(expected_dominant_offspring([usize::MAX, 0, 0, 0, 0, 1], 2), 2.0 * usize::MAX as f64);
+        let dihybrid = [Genotype::Heterozygous, Genotype::Heterozygous];
+        assert!((independent_alleles(&dihybrid, 2, 1).unwrap() - 0.684).abs() < 1e-3);
+        // 1 - P(X = 0) - P(X = 1) for X ~ B(4, 1/4).
+        assert!((independent_alleles(&dihybrid, 2, 2).unwrap() - 67.0 / 256.0).abs() < 1e-12);
+        assert_eq!(independent_alleles(&dihybrid, 3, 0).unwrap(), 1.0);
+        assert!(independent_alleles(&dihybrid, MAX_GENERATIONS + 1, 1).is_err());
+        assert_eq!(independent_alleles(&dihybrid, 1, 3).unwrap(), 0.0);
+
+        // INDC.
+        let shared = shared_chromosomes_log10(5).unwrap();
+        let expected = [0.000, -0.004, -0.024, -0.082, -0.206, -0.424, -0.765, -1.262, -1.969, -3.010];
+        assert!(shared.iter().zip(&expected).all(|(a, b)| (a - b).abs() < 1e-3));
+        // 200! overflows an f64.
+        assert!((shared_chromosomes_log10(100).unwrap()[199] + 200.0 * 2f64.log10()).abs() < 1e-9);
+        assert!(shared_chromosomes_log10(1 << 63).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use error::{Error, Result};
+use mendel::Organism;
+use tree::{NodeId, Tree};
+
+/// Genotype of the root of a pedigree, written as a tree whose leaves are
+/// labelled with known genotypes (such as `Aa` or `AaBb`) and whose other
+/// nodes are the children of their two child nodes (MEND). Labels on those
+/// other nodes are names, not genotypes, and are ignored.
+pub fn pedigree_genotype(tree: &Tree) -> Result<Organism> {
+    let mut genotypes: Vec<Option<Organism>> = vec![None; tree.len()];
+    for id in tree.postorder() {
+        let node = tree.node(id);
+        let genotype = match (node.children(), &node.label) {
+            ([], Some(label)) => label.parse()?,
+            ([], None) => return Err(Error::parse("Every individual without parents needs a genotype")),
+            (&[mother, father], _) => {
+                let parent = |id: NodeId| genotypes[id].as_ref().expect("postorder visits parents first");
+                parent(mother).cross(parent(father))?
+            }
+            (parents, _) => return Err(Error::parse(format!("An individual has {} parents, not 2", parents.len()))),
+        };
+        genotypes[id] = Some(genotype);
+    }
+    Ok(genotypes[tree.root()].take().expect("postorder visits the root"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mendel::GENOTYPES;
+
+    #[test]
+    fn sample_pedigree() {
+        let tree: Tree = "((((Aa,aa),(Aa,Aa)),((aa,aa),(aa,AA))),Aa);".parse().unwrap();
+        let root = pedigree_genotype(&tree).unwrap();
+        let expected = [0.156, 0.5, 0.344];
+        for (&genotype, expected) in GENOTYPES.iter().zip(&expected) {
+            assert!((root.loci()[0].probability(genotype) - expected).abs() < 1e-3);
+        }
+
+        assert!(pedigree_genotype(&"(Aa,aa,AA);".parse().unwrap()).is_err());
+        assert!(pedigree_genotype(&"(Aa,Ab);".parse().unwrap()).is_err());
+        assert!(pedigree_genotype(&"(Aa,AaBb);".parse().unwrap()).is_err());
+    }
+}
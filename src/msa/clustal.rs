@@ -0,0 +1,98 @@
+use std::io;
+use std::io::BufRead;
+use msa::{Alignment, invalid_data, residues_to_string};
+use seq::residue::Residue;
+
+const BLOCK_WIDTH: usize = 60;
+
+/// Read a Clustal W (`.aln`) file. Conservation lines and the optional
+/// trailing residue counts are ignored.
+pub fn read<T: Residue, R: io::Read>(reader: R) -> io::Result<Alignment<T>> {
+    let mut lines = io::BufReader::new(reader).lines();
+
+    match lines.next().transpose()? {
+        Some(ref header) if header.starts_with("CLUSTAL") => {}
+        _ => return Err(invalid_data("Expected 'CLUSTAL' header line")),
+    }
+
+    let mut rows: Vec<(String, String)> = Vec::new();
+    for line in lines {
+        let line = line?;
+        // Blank lines separate blocks and indented lines hold the conservation markup.
+        if line.trim().is_empty() || line.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let id = fields.next().unwrap_or_default();
+        let seq = fields.next()
+            .ok_or_else(|| invalid_data(format!("Missing sequence for '{}'", id)))?;
+        match rows.iter_mut().find(|row| row.0 == id) {
+            Some(row) => row.1.push_str(seq),
+            None => rows.push((id.to_owned(), seq.to_owned())),
+        }
+    }
+
+    Alignment::from_text_rows(rows)
+}
+
+pub fn write<T: Residue, W: io::Write>(alignment: &Alignment<T>, mut writer: W) -> io::Result<()> {
+    writeln!(writer, "CLUSTAL W multiple sequence alignment")?;
+
+    let id_width = alignment.rows().iter().map(|row| row.id.len()).max().unwrap_or(0) + 6;
+    let mut start = 0;
+    while start < alignment.width() {
+        let end = (start + BLOCK_WIDTH).min(alignment.width());
+        writeln!(writer)?;
+        for row in alignment.rows() {
+            writeln!(writer, "{:width$}{}", row.id, residues_to_string(&row.seq[start..end]), width = id_width)?;
+        }
+        let conservation: String = (start..end)
+            .map(|i| match alignment.column(i) {
+                Some(ref col) if !col[0].is_gap() && col.iter().all(|r| *r == col[0]) => '*',
+                _ => ' ',
+            })
+            .collect();
+        writeln!(writer, "{:width$}{}", "", conservation, width = id_width)?;
+        start = end;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seq::translation::TranslatedCodon;
+
+    const CLUSTAL: &[u8] = b"CLUSTAL W (1.83) multiple sequence alignment
+
+seqA      MKV-LA 5
+seqB      MKVQLA 6
+          *** **
+
+seqA      GW
+seqB      G-
+          *
+";
+
+    #[test]
+    fn read_blocks() {
+        let alignment: Alignment<TranslatedCodon> = read(CLUSTAL).unwrap();
+        assert_eq!(alignment.len(), 2);
+        assert_eq!(alignment.width(), 8);
+        assert_eq!(residues_to_string(&alignment.rows()[1].seq), "MKVQLAG-");
+    }
+
+    #[test]
+    fn round_trip() {
+        let alignment: Alignment<TranslatedCodon> = read(CLUSTAL).unwrap();
+        let mut out = Vec::new();
+        write(&alignment, &mut out).unwrap();
+        let again: Alignment<TranslatedCodon> = read(&out[..]).unwrap();
+        assert_eq!(alignment, again);
+    }
+
+    #[test]
+    fn missing_header() {
+        assert!(read::<TranslatedCodon, _>(&b"seqA MKV\n"[..]).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use std::io;
+use io::{FastaError, FastaReader};
+use msa::{Alignment, residues_to_string};
+use seq::residue::Residue;
+
+const LINE_WIDTH: usize = 60;
+
+/// Read an aligned FASTA file, where gaps are written inline as `-` or `.`.
+pub fn read<T: Residue, R: io::Read>(reader: R) -> io::Result<Alignment<T>> {
+    let rows = FastaReader::new(reader)
+        .map(|record| record.map(|rec| (rec.id().clone(), rec.seq().clone())))
+        .collect::<Result<Vec<_>, FastaError>>()?;
+    Alignment::from_text_rows(rows)
+}
+
+pub fn write<T: Residue, W: io::Write>(alignment: &Alignment<T>, mut writer: W) -> io::Result<()> {
+    for row in alignment.rows() {
+        writeln!(writer, ">{}", row.id)?;
+        for chunk in row.seq.chunks(LINE_WIDTH) {
+            writeln!(writer, "{}", residues_to_string(chunk))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seq::nuc::DNA;
+
+    const ALIGNED: &[u8] = b">seq1 first
+ACG-T
+>seq2
+A-GNT
+";
+
+    #[test]
+    fn round_trip() {
+        let alignment: Alignment<DNA> = read(ALIGNED).unwrap();
+        assert_eq!(alignment.len(), 2);
+        assert_eq!(alignment.rows()[1].seq, vec![DNA::A, DNA::Gap, DNA::G, DNA::N, DNA::T]);
+
+        let mut out = Vec::new();
+        write(&alignment, &mut out).unwrap();
+        assert_eq!(out, b">seq1\nACG-T\n>seq2\nA-GNT\n".to_vec());
+    }
+
+    #[test]
+    fn unequal_rows() {
+        assert!(read::<DNA, _>(&b">a\nACGT\n>b\nACG\n"[..]).is_err());
+    }
+}
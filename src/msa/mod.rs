@@ -0,0 +1,201 @@
+use std::io;
+use std::str::FromStr;
+use seq::residue::Residue;
+
+pub mod fasta;
+pub mod clustal;
+pub mod stockholm;
+pub mod phylip;
+
+/// A single named row of an alignment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row<T> {
+    pub id: String,
+    pub seq: Vec<T>,
+}
+
+/// Stockholm markup that doesn't belong to any one residue row. It is kept
+/// verbatim so that it survives a read/write round trip.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Annotations {
+    /// `#=GF <tag> <text>` lines, in file order.
+    pub file: Vec<(String, String)>,
+    /// `#=GS <id> <tag> <text>` lines, in file order.
+    pub sequence: Vec<(String, String, String)>,
+    /// `#=GC <tag> <markup>`, concatenated across blocks.
+    pub column: Vec<(String, String)>,
+    /// `#=GR <id> <tag> <markup>`, concatenated across blocks.
+    pub residue: Vec<(String, String, String)>,
+}
+
+impl Annotations {
+    pub fn is_empty(&self) -> bool {
+        self.file.is_empty() && self.sequence.is_empty() && self.column.is_empty() && self.residue.is_empty()
+    }
+}
+
+/// A multiple sequence alignment: equal-length, gapped rows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignment<T> {
+    rows: Vec<Row<T>>,
+    pub annotations: Annotations,
+}
+
+impl<T: Residue> Default for Alignment<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Residue> Alignment<T> {
+    pub fn new() -> Self {
+        Alignment {
+            rows: Vec::new(),
+            annotations: Annotations::default(),
+        }
+    }
+
+    /// Add a row, rejecting it if its length differs from the rows already present.
+    pub fn push(&mut self, id: String, seq: Vec<T>) -> io::Result<()> {
+        if let Some(width) = self.rows.first().map(|row| row.seq.len()) {
+            if seq.len() != width {
+                return Err(invalid_data(format!(
+                    "Row '{}' has {} columns, expected {}", id, seq.len(), width)));
+            }
+        }
+        self.rows.push(Row { id, seq });
+        Ok(())
+    }
+
+    /// Parse textual rows into residues and build an alignment from them.
+    pub fn from_text_rows<I: IntoIterator<Item = (String, String)>>(rows: I) -> io::Result<Self> {
+        let mut alignment = Alignment::new();
+        for (id, text) in rows {
+            let seq = parse_residues(&id, &text)?;
+            alignment.push(id, seq)?;
+        }
+        Ok(alignment)
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.seq.len())
+    }
+
+    pub fn rows(&self) -> &[Row<T>] {
+        &self.rows
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Row<T>> {
+        self.rows.iter().find(|row| row.id == id)
+    }
+
+    pub fn column(&self, idx: usize) -> Option<Vec<T>> {
+        if idx >= self.width() {
+            return None;
+        }
+        Some(self.rows.iter().map(|row| row.seq[idx]).collect())
+    }
+
+    pub fn read<R: io::Read>(reader: R, format: Format) -> io::Result<Self> {
+        match format {
+            Format::Fasta => fasta::read(reader),
+            Format::Clustal => clustal::read(reader),
+            Format::Stockholm => stockholm::read(reader),
+            Format::Phylip(layout) => phylip::read(reader, layout),
+        }
+    }
+
+    pub fn write<W: io::Write>(&self, writer: W, format: Format) -> io::Result<()> {
+        match format {
+            Format::Fasta => fasta::write(self, writer),
+            Format::Clustal => clustal::write(self, writer),
+            Format::Stockholm => stockholm::write(self, writer),
+            Format::Phylip(layout) => phylip::write(self, writer, layout),
+        }
+    }
+}
+
+/// How PHYLIP files lay out sequences that don't fit on one line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhylipLayout {
+    /// Each sequence is given in full before the next one starts.
+    Sequential,
+    /// Sequences are given in blocks of columns, names only in the first block.
+    Interleaved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Fasta,
+    Clustal,
+    Stockholm,
+    Phylip(PhylipLayout),
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "fasta" | "afa" => Ok(Format::Fasta),
+            "clustal" | "aln" => Ok(Format::Clustal),
+            "stockholm" | "sto" => Ok(Format::Stockholm),
+            "phylip" | "phylip-sequential" => Ok(Format::Phylip(PhylipLayout::Sequential)),
+            "phylip-interleaved" => Ok(Format::Phylip(PhylipLayout::Interleaved)),
+            _ => Err(format!("Unknown alignment format '{}'", s)),
+        }
+    }
+}
+
+/// Names accepted by `Format::from_str`, for use in help text and validators.
+pub const FORMAT_NAMES: &[&str] = &["fasta", "clustal", "stockholm", "phylip", "phylip-interleaved"];
+
+fn parse_residues<T: Residue>(id: &str, text: &str) -> io::Result<Vec<T>> {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| T::from_char(c).ok_or_else(|| invalid_data(
+            format!("Invalid residue '{}' in row '{}'", c, id))))
+        .collect()
+}
+
+fn residues_to_string<T: Residue>(seq: &[T]) -> String {
+    seq.iter().map(|r| r.to_char()).collect()
+}
+
+fn invalid_data<E: Into<String>>(message: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seq::nuc::DNA;
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let mut alignment = Alignment::new();
+        alignment.push("a".to_owned(), vec![DNA::A, DNA::Gap]).unwrap();
+        assert!(alignment.push("b".to_owned(), vec![DNA::A]).is_err());
+        assert_eq!(alignment.width(), 2);
+        assert_eq!(alignment.column(1), Some(vec![DNA::Gap]));
+    }
+
+    #[test]
+    fn format_names() {
+        for name in FORMAT_NAMES {
+            assert!(name.parse::<Format>().is_ok());
+        }
+        assert_eq!("sto".parse(), Ok(Format::Stockholm));
+        assert!("nexus".parse::<Format>().is_err());
+    }
+}
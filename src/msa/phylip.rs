@@ -0,0 +1,149 @@
+use std::io;
+use std::io::BufRead;
+use msa::{Alignment, PhylipLayout, invalid_data, residues_to_string};
+use seq::residue::Residue;
+
+const BLOCK_WIDTH: usize = 60;
+const MIN_NAME_WIDTH: usize = 10;
+
+/// Read a relaxed PHYLIP file: names are separated from their sequence by
+/// whitespace rather than padded to exactly ten characters, and whitespace
+/// inside sequences is ignored.
+pub fn read<T: Residue, R: io::Read>(reader: R, layout: PhylipLayout) -> io::Result<Alignment<T>> {
+    let mut lines = io::BufReader::new(reader)
+        .lines()
+        .filter(|line| line.as_ref().map(|l| !l.trim().is_empty()).unwrap_or(true));
+
+    let header = lines.next().transpose()?
+        .ok_or_else(|| invalid_data("Expected PHYLIP header with sequence count and length"))?;
+    let dimensions = header.split_whitespace()
+        .take(2)
+        .map(|field| field.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| invalid_data(format!("Invalid PHYLIP header '{}': {}", header, e)))?;
+    let (count, width) = match dimensions[..] {
+        [count, width] => (count, width),
+        _ => return Err(invalid_data(format!("Invalid PHYLIP header '{}'", header))),
+    };
+
+    // The count comes from the file, so rows are only allocated as they're read.
+    let mut rows: Vec<(String, String)> = Vec::new();
+    let mut next_line = || lines.next()
+        .unwrap_or_else(|| Err(invalid_data("Unexpected end of PHYLIP file")));
+
+    match layout {
+        PhylipLayout::Sequential => {
+            for _ in 0..count {
+                let mut row = split_name(&next_line()?);
+                while row.1.len() < width {
+                    row.1.extend(next_line()?.split_whitespace());
+                }
+                rows.push(row);
+            }
+        }
+        PhylipLayout::Interleaved => {
+            for _ in 0..count {
+                rows.push(split_name(&next_line()?));
+            }
+            let mut idx = 0;
+            while rows.iter().any(|row| row.1.len() < width) {
+                rows[idx].1.extend(next_line()?.split_whitespace());
+                idx = (idx + 1) % count;
+            }
+        }
+    }
+
+    if let Some(row) = rows.iter().find(|row| row.1.len() != width) {
+        return Err(invalid_data(format!(
+            "Row '{}' has {} columns, header says {}", row.0, row.1.len(), width)));
+    }
+    Alignment::from_text_rows(rows)
+}
+
+pub fn write<T: Residue, W: io::Write>(alignment: &Alignment<T>, mut writer: W, layout: PhylipLayout) -> io::Result<()> {
+    writeln!(writer, " {} {}", alignment.len(), alignment.width())?;
+
+    let name_width = alignment.rows().iter()
+        .map(|row| row.id.len() + 1)
+        .max()
+        .unwrap_or(0)
+        .max(MIN_NAME_WIDTH);
+
+    match layout {
+        PhylipLayout::Sequential => {
+            for row in alignment.rows() {
+                writeln!(writer, "{:width$}{}", row.id, residues_to_string(&row.seq), width = name_width)?;
+            }
+        }
+        PhylipLayout::Interleaved => {
+            let mut start = 0;
+            while start < alignment.width() {
+                let end = (start + BLOCK_WIDTH).min(alignment.width());
+                if start > 0 {
+                    writeln!(writer)?;
+                }
+                for row in alignment.rows() {
+                    let name = if start == 0 { row.id.as_str() } else { "" };
+                    writeln!(writer, "{:width$}{}", name, residues_to_string(&row.seq[start..end]), width = name_width)?;
+                }
+                start = end;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn split_name(line: &str) -> (String, String) {
+    let line = line.trim_start();
+    let idx = line.find(char::is_whitespace).unwrap_or(line.len());
+    (line[..idx].to_owned(), line[idx..].split_whitespace().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seq::nuc::DNA;
+
+    const SEQUENTIAL: &[u8] = b" 3 14
+Turkey    AAGCTNGGGC
+ATTT
+Salmo_gair AAGCCTTGGC AGTG
+H.        ACCGGTTGGC
+CGTT
+";
+
+    const INTERLEAVED: &[u8] = b" 3 14
+Turkey    AAGCTNGG
+Salmo_gair AAGCCTTG
+H.        ACCGGTTG
+
+GCATTT
+GCAGTG
+GCCGTT
+";
+
+    #[test]
+    fn layouts_agree() {
+        let sequential: Alignment<DNA> = read(SEQUENTIAL, PhylipLayout::Sequential).unwrap();
+        let interleaved: Alignment<DNA> = read(INTERLEAVED, PhylipLayout::Interleaved).unwrap();
+        assert_eq!(sequential, interleaved);
+        assert_eq!(sequential.rows()[1].id, "Salmo_gair");
+        assert_eq!(residues_to_string(&sequential.rows()[2].seq), "ACCGGTTGGCCGTT");
+    }
+
+    #[test]
+    fn round_trip() {
+        let alignment: Alignment<DNA> = read(SEQUENTIAL, PhylipLayout::Sequential).unwrap();
+        for &layout in &[PhylipLayout::Sequential, PhylipLayout::Interleaved] {
+            let mut out = Vec::new();
+            write(&alignment, &mut out, layout).unwrap();
+            assert_eq!(read::<DNA, _>(&out[..], layout).unwrap(), alignment);
+        }
+    }
+
+    #[test]
+    fn truncated_file() {
+        assert!(read::<DNA, _>(&b" 2 4\na ACGT\n"[..], PhylipLayout::Sequential).is_err());
+        assert!(read::<DNA, _>(&b" 18446744073709551615 4\na ACGT\n"[..], PhylipLayout::Sequential).is_err());
+    }
+}
@@ -0,0 +1,161 @@
+use std::io;
+use std::io::BufRead;
+use msa::{Alignment, invalid_data, parse_residues, residues_to_string};
+use seq::residue::Residue;
+
+/// Read a single Stockholm alignment. `#=GF`, `#=GS`, `#=GC` and `#=GR`
+/// markup is kept in `Alignment::annotations`; other comments are dropped.
+pub fn read<T: Residue, R: io::Read>(reader: R) -> io::Result<Alignment<T>> {
+    let mut lines = io::BufReader::new(reader).lines();
+
+    match lines.next().transpose()? {
+        Some(ref header) if header.starts_with("# STOCKHOLM") => {}
+        _ => return Err(invalid_data("Expected '# STOCKHOLM 1.0' header line")),
+    }
+
+    let mut rows: Vec<(String, String)> = Vec::new();
+    let mut alignment = Alignment::new();
+    let mut terminated = false;
+
+    for line in lines {
+        let line = line?;
+        let line = line.trim_end();
+        if line == "//" {
+            terminated = true;
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let annotations = &mut alignment.annotations;
+        if let Some(rest) = line.strip_prefix("#=GF") {
+            let (tag, text) = split_field(rest);
+            annotations.file.push((tag.to_owned(), text.to_owned()));
+        } else if let Some(rest) = line.strip_prefix("#=GS") {
+            let (id, rest) = split_field(rest);
+            let (tag, text) = split_field(rest);
+            annotations.sequence.push((id.to_owned(), tag.to_owned(), text.to_owned()));
+        } else if let Some(rest) = line.strip_prefix("#=GC") {
+            let (tag, markup) = split_field(rest);
+            match annotations.column.iter_mut().find(|gc| gc.0 == tag) {
+                Some(gc) => gc.1.push_str(markup),
+                None => annotations.column.push((tag.to_owned(), markup.to_owned())),
+            }
+        } else if let Some(rest) = line.strip_prefix("#=GR") {
+            let (id, rest) = split_field(rest);
+            let (tag, markup) = split_field(rest);
+            match annotations.residue.iter_mut().find(|gr| gr.0 == id && gr.1 == tag) {
+                Some(gr) => gr.2.push_str(markup),
+                None => annotations.residue.push((id.to_owned(), tag.to_owned(), markup.to_owned())),
+            }
+        } else if line.starts_with('#') {
+            continue;
+        } else {
+            let (id, seq) = split_field(line);
+            match rows.iter_mut().find(|row| row.0 == id) {
+                Some(row) => row.1.push_str(seq),
+                None => rows.push((id.to_owned(), seq.to_owned())),
+            }
+        }
+    }
+
+    if !terminated {
+        return Err(invalid_data("Missing '//' at end of Stockholm alignment"));
+    }
+
+    for (id, text) in rows {
+        let seq = parse_residues(&id, &text)?;
+        alignment.push(id, seq)?;
+    }
+    Ok(alignment)
+}
+
+pub fn write<T: Residue, W: io::Write>(alignment: &Alignment<T>, mut writer: W) -> io::Result<()> {
+    let annotations = &alignment.annotations;
+    writeln!(writer, "# STOCKHOLM 1.0")?;
+
+    for (tag, text) in &annotations.file {
+        writeln!(writer, "#=GF {} {}", tag, text)?;
+    }
+    for (id, tag, text) in &annotations.sequence {
+        writeln!(writer, "#=GS {} {} {}", id, tag, text)?;
+    }
+    if !annotations.file.is_empty() || !annotations.sequence.is_empty() {
+        writeln!(writer)?;
+    }
+
+    // Pad every name column to the same width so the residues line up.
+    let width = alignment.rows().iter().map(|row| row.id.len())
+        .chain(annotations.residue.iter().map(|gr| gr.0.len() + gr.1.len() + 6))
+        .chain(annotations.column.iter().map(|gc| gc.0.len() + 5))
+        .max()
+        .unwrap_or(0) + 1;
+
+    for row in alignment.rows() {
+        writeln!(writer, "{:width$}{}", row.id, residues_to_string(&row.seq), width = width)?;
+        for (_, tag, markup) in annotations.residue.iter().filter(|gr| gr.0 == row.id) {
+            let name = format!("#=GR {} {}", row.id, tag);
+            writeln!(writer, "{:width$}{}", name, markup, width = width)?;
+        }
+    }
+    for (tag, markup) in &annotations.column {
+        let name = format!("#=GC {}", tag);
+        writeln!(writer, "{:width$}{}", name, markup, width = width)?;
+    }
+    writeln!(writer, "//")
+}
+
+/// Split off the first whitespace-delimited field, returning it and the trimmed remainder.
+fn split_field(text: &str) -> (&str, &str) {
+    let text = text.trim_start();
+    match text.find(char::is_whitespace) {
+        Some(idx) => (&text[..idx], text[idx..].trim()),
+        None => (text, ""),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use seq::nuc::DNA;
+
+    const STOCKHOLM: &[u8] = b"# STOCKHOLM 1.0
+#=GF ID    test-family
+#=GS seq1  AC P00001
+
+seq1          ACG-T
+#=GR seq1 SS  <<.>>
+seq2          AC..T
+#=GC SS_cons  <<.>>
+
+seq1          AA
+seq2          A-
+#=GC SS_cons  ..
+//
+";
+
+    #[test]
+    fn read_annotations() {
+        let alignment: Alignment<DNA> = read(STOCKHOLM).unwrap();
+        assert_eq!(alignment.width(), 7);
+        assert_eq!(alignment.annotations.file, vec![("ID".to_owned(), "test-family".to_owned())]);
+        assert_eq!(alignment.annotations.sequence[0].2, "P00001");
+        assert_eq!(alignment.annotations.column, vec![("SS_cons".to_owned(), "<<.>>..".to_owned())]);
+        assert_eq!(alignment.annotations.residue[0].2, "<<.>>");
+    }
+
+    #[test]
+    fn round_trip() {
+        let alignment: Alignment<DNA> = read(STOCKHOLM).unwrap();
+        let mut out = Vec::new();
+        write(&alignment, &mut out).unwrap();
+        let again: Alignment<DNA> = read(&out[..]).unwrap();
+        assert_eq!(alignment, again);
+    }
+
+    #[test]
+    fn missing_terminator() {
+        assert!(read::<DNA, _>(&b"# STOCKHOLM 1.0\nseq1 ACGT\n"[..]).is_err());
+    }
+}
@@ -0,0 +1,331 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub};
+use std::str::FromStr;
+
+const LIMB_BITS: usize = 32;
+/// Largest power of ten that fits in a limb, for converting to and from decimal.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 9;
+
+/// An arbitrary-precision unsigned integer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without trailing zeros.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint::default()
+    }
+
+    pub fn one() -> Self {
+        BigUint::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// The value as a `u64`, if it fits.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(u64::from(self.limbs[0])),
+            2 => Some(u64::from(self.limbs[0]) | u64::from(self.limbs[1]) << LIMB_BITS),
+            _ => None,
+        }
+    }
+
+    /// Number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(&top) => self.limbs.len() * LIMB_BITS - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    fn bit(&self, idx: usize) -> bool {
+        self.limbs.get(idx / LIMB_BITS).is_some_and(|&limb| limb >> (idx % LIMB_BITS) & 1 == 1)
+    }
+
+    fn set_bit(&mut self, idx: usize) {
+        let limb = idx / LIMB_BITS;
+        if self.limbs.len() <= limb {
+            self.limbs.resize(limb + 1, 0);
+        }
+        self.limbs[limb] |= 1 << (idx % LIMB_BITS);
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    fn shl1_with(&mut self, low_bit: bool) {
+        let mut carry = u32::from(low_bit);
+        for limb in &mut self.limbs {
+            let next = *limb >> (LIMB_BITS - 1);
+            *limb = *limb << 1 | carry;
+            carry = next;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+
+    /// `self - other`, or `None` if that would be negative.
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if *self < *other {
+            return None;
+        }
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0i64;
+        for (idx, &limb) in self.limbs.iter().enumerate() {
+            let mut diff = i64::from(limb) - i64::from(other.limbs.get(idx).cloned().unwrap_or(0)) - borrow;
+            borrow = if diff < 0 { diff += 1 << LIMB_BITS; 1 } else { 0 };
+            limbs.push(diff as u32);
+        }
+        let mut result = BigUint { limbs };
+        result.normalize();
+        Some(result)
+    }
+
+    /// Quotient and remainder by a single limb.
+    fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        let mut limbs = vec![0; self.limbs.len()];
+        let mut rem = 0u64;
+        for idx in (0..self.limbs.len()).rev() {
+            let current = rem << LIMB_BITS | u64::from(self.limbs[idx]);
+            limbs[idx] = (current / u64::from(divisor)) as u32;
+            rem = current % u64::from(divisor);
+        }
+        let mut quotient = BigUint { limbs };
+        quotient.normalize();
+        (quotient, rem as u32)
+    }
+
+    /// Quotient and remainder, or `None` when dividing by zero.
+    pub fn checked_div_rem(&self, divisor: &BigUint) -> Option<(BigUint, BigUint)> {
+        match divisor.limbs.len() {
+            0 => None,
+            1 => {
+                let (quotient, rem) = self.div_rem_small(divisor.limbs[0]);
+                Some((quotient, BigUint::from(u64::from(rem))))
+            }
+            _ => {
+                // Binary long division: fine for the sizes counting problems produce.
+                let mut quotient = BigUint::zero();
+                let mut rem = BigUint::zero();
+                for idx in (0..self.bits()).rev() {
+                    rem.shl1_with(self.bit(idx));
+                    if rem >= *divisor {
+                        rem = rem.checked_sub(divisor).expect("rem >= divisor");
+                        quotient.set_bit(idx);
+                    }
+                }
+                Some((quotient, rem))
+            }
+        }
+    }
+
+    /// Quotient and remainder.
+    ///
+    /// # Panics
+    /// If `divisor` is zero, like the built-in integer types.
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        self.checked_div_rem(divisor).expect("attempt to divide by zero")
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        let mut result = BigUint { limbs: vec![n as u32, (n >> LIMB_BITS) as u32] };
+        result.normalize();
+        result
+    }
+}
+
+impl From<usize> for BigUint {
+    fn from(n: usize) -> Self {
+        BigUint::from(n as u64)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.len().cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Add<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() { (self, other) } else { (other, self) };
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = 0u64;
+        for (idx, &limb) in long.limbs.iter().enumerate() {
+            let sum = u64::from(limb) + u64::from(short.limbs.get(idx).cloned().unwrap_or(0)) + carry;
+            limbs.push(sum as u32);
+            carry = sum >> LIMB_BITS;
+        }
+        if carry != 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: BigUint) -> BigUint {
+        &self + &other
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, other: &BigUint) {
+        *self = &*self + other;
+    }
+}
+
+impl<'a> Mul<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let product = u64::from(a) * u64::from(b) + u64::from(limbs[i + j]) + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> LIMB_BITS;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        let mut result = BigUint { limbs };
+        result.normalize();
+        result
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: BigUint) -> BigUint {
+        &self * &other
+    }
+}
+
+/// # Panics
+/// If the result would be negative, like the built-in unsigned types.
+impl<'a> Sub<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other).expect("attempt to subtract with overflow")
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem_small(DECIMAL_CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut digits = chunks.pop().expect("non-zero").to_string();
+        for chunk in chunks.iter().rev() {
+            digits += &format!("{:0width$}", chunk, width = DECIMAL_CHUNK_DIGITS);
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("'{}' is not a non-negative integer", s));
+        }
+        let chunk = BigUint::from(u64::from(DECIMAL_CHUNK));
+        let mut result = BigUint::zero();
+        let head = s.len() % DECIMAL_CHUNK_DIGITS;
+        let mut rest = s;
+        if head > 0 {
+            result = BigUint::from(s[..head].parse::<u64>().map_err(|e| e.to_string())?);
+            rest = &s[head..];
+        }
+        while !rest.is_empty() {
+            let digits: u64 = rest[..DECIMAL_CHUNK_DIGITS].parse().map_err(|e: ::std::num::ParseIntError| e.to_string())?;
+            result = &(&result * &chunk) + &BigUint::from(digits);
+            rest = &rest[DECIMAL_CHUNK_DIGITS..];
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let max = BigUint::from(u64::MAX);
+        assert_eq!((&max + &BigUint::one()).to_string(), "18446744073709551616");
+        assert_eq!((&max * &max).to_string(), "340282366920938463426481119284349108225");
+        assert_eq!(&big("18446744073709551616") - &BigUint::one(), max);
+        assert_eq!(BigUint::one().checked_sub(&max), None);
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(format!("{:>5}", BigUint::from(42u64)), "   42");
+        assert_eq!(max.to_u64(), Some(u64::MAX));
+        assert_eq!((&max + &max).to_u64(), None);
+    }
+
+    #[test]
+    fn division() {
+        let n = big("354224848179261915075");
+        let (q, r) = n.div_rem(&big("1000000"));
+        assert_eq!((q.to_string(), r.to_string()), ("354224848179261".to_owned(), "915075".to_owned()));
+
+        let d = big("98765432109876543210");
+        let (q, r) = n.div_rem(&d);
+        assert_eq!(&(&q * &d) + &r, n);
+        assert!(r < d);
+        assert_eq!(n.checked_div_rem(&BigUint::zero()), None);
+    }
+
+    #[test]
+    fn parsing() {
+        for s in &["0", "7", "1000000000", "123456789012345678901234567890"] {
+            assert_eq!(big(s).to_string(), *s);
+        }
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("".parse::<BigUint>().is_err());
+        assert!("-1".parse::<BigUint>().is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use std::f64::consts::PI;
+
+/// Below this, `ln_factorial` sums logarithms; above, Stirling's series is
+/// accurate to machine precision.
+const STIRLING_THRESHOLD: u64 = 256;
+
+/// `ln(n!)`, accurate even where `n!` itself would overflow an `f64`.
+pub fn ln_factorial(n: u64) -> f64 {
+    if n < STIRLING_THRESHOLD {
+        return (2..=n).map(|k| (k as f64).ln()).sum();
+    }
+    let n = n as f64;
+    n * n.ln() - n + 0.5 * (2.0 * PI * n).ln() + 1.0 / (12.0 * n) - 1.0 / (360.0 * n.powi(3)) + 1.0 / (1260.0 * n.powi(5))
+}
+
+/// `ln(n choose k)`; negative infinity when `k > n`.
+pub fn ln_choose(n: u64, k: u64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k)
+}
+
+/// `ln P(X = k)` for `X` binomially distributed with `n` trials of chance `p`.
+pub fn binomial_ln_pmf(n: u64, k: u64, p: f64) -> f64 {
+    if k > n {
+        return f64::NEG_INFINITY;
+    }
+    // Spelled out so that 0 ln 0 counts as 0 at the edges.
+    let successes = if k == 0 { 0.0 } else { k as f64 * p.ln() };
+    let failures = if k == n { 0.0 } else { (n - k) as f64 * (1.0 - p).ln() };
+    ln_choose(n, k) + successes + failures
+}
+
+/// `ln P(X >= at_least)` for `X` binomially distributed with `n` trials of
+/// chance `p`, summed in log space so tiny tails stay accurate.
+pub fn binomial_ln_tail(n: u64, at_least: u64, p: f64) -> f64 {
+    if at_least == 0 {
+        return 0.0;
+    }
+    // Rounding can push a sum of chances just past 1.
+    log_sum_exp((at_least..=n).map(|k| binomial_ln_pmf(n, k, p))).min(0.0)
+}
+
+/// `ln(exp(a) + exp(b))`, without overflow or underflow.
+pub fn ln_add(a: f64, b: f64) -> f64 {
+    let (high, low) = if a >= b { (a, b) } else { (b, a) };
+    if low == f64::NEG_INFINITY {
+        return high;
+    }
+    high + (low - high).exp().ln_1p()
+}
+
+/// `ln(sum(exp(x)))` over `ln_values`, without overflow or underflow.
+/// Negative infinity, the log of zero, for no values. The values are
+/// streamed, rescaling the running sum whenever a new maximum turns up.
+pub fn log_sum_exp<I: IntoIterator<Item = f64>>(ln_values: I) -> f64 {
+    let (max, sum) = ln_values.into_iter().fold((f64::NEG_INFINITY, 0.0), |(max, sum), x| {
+        if x == f64::NEG_INFINITY {
+            (max, sum)
+        } else if x > max {
+            (x, sum * (max - x).exp() + 1.0)
+        } else {
+            (max, sum + (x - max).exp())
+        }
+    });
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+    max + sum.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn factorials() {
+        assert_eq!(ln_factorial(0), 0.0);
+        assert!(close(ln_factorial(10), 3628800f64.ln()));
+        // Both sides of the switch to Stirling's series agree.
+        let summed: f64 = (2..=STIRLING_THRESHOLD).map(|k| (k as f64).ln()).sum();
+        assert!(close(ln_factorial(STIRLING_THRESHOLD), summed));
+        assert!(close(ln_choose(52, 5), 2598960f64.ln()));
+        assert_eq!(ln_choose(3, 4), f64::NEG_INFINITY);
+        // 10000 choose 5000 has over 3000 digits.
+        assert!(close(ln_choose(10000, 5000), 6926.640819060805));
+    }
+
+    #[test]
+    fn binomials() {
+        assert!(close(binomial_ln_pmf(4, 2, 0.5).exp(), 0.375));
+        assert_eq!(binomial_ln_pmf(4, 0, 0.0), 0.0);
+        assert_eq!(binomial_ln_pmf(4, 4, 1.0), 0.0);
+        assert_eq!(binomial_ln_pmf(4, 1, 0.0), f64::NEG_INFINITY);
+        let total = log_sum_exp((0..=100).map(|k| binomial_ln_pmf(100, k, 0.3)));
+        assert!(close(total, 0.0));
+        assert!(close(log_sum_exp(vec![-1000.0, -1000.0]), -1000.0 + 2f64.ln()));
+        assert_eq!(log_sum_exp(vec![]), f64::NEG_INFINITY);
+        assert!(close(ln_add(-1000.0, -1000.0), -1000.0 + 2f64.ln()));
+        assert_eq!(ln_add(f64::NEG_INFINITY, -3.0), -3.0);
+        assert!(close(log_sum_exp(vec![-1.0, f64::NEG_INFINITY, 3.0, 0.5]), ((-1f64).exp() + 3f64.exp() + 0.5f64.exp()).ln()));
+
+        assert_eq!(binomial_ln_tail(10, 0, 0.5), 0.0);
+        assert!(close(binomial_ln_tail(4, 2, 0.25).exp(), 67.0 / 256.0));
+        assert_eq!(binomial_ln_tail(4, 5, 0.25), f64::NEG_INFINITY);
+        // 2^-1000 underflows as a probability but not as a logarithm.
+        assert!(close(binomial_ln_tail(1000, 1000, 0.5), -1000.0 * 2f64.ln()));
+    }
+}
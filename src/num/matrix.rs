@@ -0,0 +1,114 @@
+use num::Count;
+
+/// A square matrix of counts, for linear recurrences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T> {
+    size: usize,
+    /// Entries row by row.
+    entries: Vec<T>,
+}
+
+impl<T: Count> Matrix<T> {
+    pub fn zero(size: usize) -> Self {
+        Matrix { size, entries: vec![T::zero(); size * size] }
+    }
+
+    pub fn identity(size: usize) -> Self {
+        let mut identity = Matrix::zero(size);
+        for idx in 0..size {
+            identity.set(idx, idx, T::one());
+        }
+        identity
+    }
+
+    /// Number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &T {
+        &self.entries[row * self.size + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self.entries[row * self.size + col] = value;
+    }
+
+    /// `self × other`, or `None` if an entry can't be represented.
+    ///
+    /// # Panics
+    /// If the matrices are different sizes.
+    pub fn checked_mul(&self, other: &Matrix<T>) -> Option<Matrix<T>> {
+        assert_eq!(self.size, other.size, "matrix sizes differ");
+        let mut product = Matrix::zero(self.size);
+        for row in 0..self.size {
+            for col in 0..self.size {
+                let mut sum = T::zero();
+                for idx in 0..self.size {
+                    sum = sum.checked_add(&self.get(row, idx).checked_mul(other.get(idx, col))?)?;
+                }
+                product.set(row, col, sum);
+            }
+        }
+        Some(product)
+    }
+
+    /// `self × vector`, or `None` if an entry can't be represented.
+    ///
+    /// # Panics
+    /// If the vector's length isn't the matrix size.
+    pub fn checked_mul_vec(&self, vector: &[T]) -> Option<Vec<T>> {
+        assert_eq!(self.size, vector.len(), "vector length differs from matrix size");
+        (0..self.size)
+            .map(|row| vector.iter().enumerate().try_fold(T::zero(), |sum, (col, value)| {
+                sum.checked_add(&self.get(row, col).checked_mul(value)?)
+            }))
+            .collect()
+    }
+
+    /// `self` raised to `exp` by repeated squaring, taking `O(log exp)`
+    /// multiplications. Never squares beyond what the answer needs, so for
+    /// non-negative entries it only fails when the answer itself overflows.
+    pub fn checked_pow(&self, mut exp: u64) -> Option<Matrix<T>> {
+        let mut result = Matrix::identity(self.size);
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(&base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::BigUint;
+
+    fn fibonacci<T: Count>() -> Matrix<T> {
+        let mut matrix = Matrix::zero(2);
+        matrix.set(0, 1, T::one());
+        matrix.set(1, 0, T::one());
+        matrix.set(1, 1, T::one());
+        matrix
+    }
+
+    #[test]
+    fn powers() {
+        let power = fibonacci::<usize>().checked_pow(10).unwrap();
+        assert_eq!((*power.get(0, 1), *power.get(1, 1)), (55, 89));
+        assert_eq!(fibonacci::<usize>().checked_pow(0), Some(Matrix::identity(2)));
+        assert_eq!(power.checked_mul_vec(&[1, 0]), Some(vec![34, 55]));
+        // F(93) is the largest Fibonacci number that fits in 64 bits.
+        assert!(fibonacci::<u64>().checked_pow(92).is_some());
+        assert!(fibonacci::<u64>().checked_pow(93).is_none());
+
+        let big = fibonacci::<BigUint>().checked_pow(100).unwrap();
+        assert_eq!(big.get(0, 1).to_string(), "354224848179261915075");
+    }
+}
@@ -0,0 +1,77 @@
+//! Exact and modular integers for counting problems whose answers outgrow
+//! `usize`, and log-space combinatorics for probabilities that would underflow.
+
+mod biguint;
+mod combinatorics;
+mod matrix;
+mod modint;
+
+pub use self::biguint::BigUint;
+pub use self::combinatorics::{binomial_ln_pmf, binomial_ln_tail, ln_add, ln_choose, ln_factorial, log_sum_exp};
+pub use self::matrix::Matrix;
+pub use self::modint::{ModInt, ROSALIND_MODULUS};
+
+/// The arithmetic counting recurrences need. Additions and multiplications
+/// return `None` only when the result can't be represented.
+pub trait Count: Clone {
+    fn from_usize(n: usize) -> Self;
+
+    fn zero() -> Self {
+        Self::from_usize(0)
+    }
+
+    fn one() -> Self {
+        Self::from_usize(1)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! count_primitive {
+    ($($ty:ty),*) => {
+        $(impl Count for $ty {
+            fn from_usize(n: usize) -> Self {
+                n as $ty
+            }
+
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                <$ty>::checked_add(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$ty>::checked_mul(*self, *other)
+            }
+        })*
+    };
+}
+
+count_primitive!(usize, u64);
+
+impl Count for BigUint {
+    fn from_usize(n: usize) -> Self {
+        BigUint::from(n)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+impl<const M: u64> Count for ModInt<M> {
+    fn from_usize(n: usize) -> Self {
+        ModInt::new(n as u64)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(*self + *other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(*self * *other)
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+/// The modulus Rosalind uses for answers too large to print in full.
+pub const ROSALIND_MODULUS: u64 = 1_000_000;
+
+/// An integer modulo `M`. `M` must be at least 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(n: u64) -> Self {
+        ModInt(n % M)
+    }
+
+    /// The representative in `0..M`.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// `self` raised to `exp`, by repeated squaring.
+    pub fn pow(self, mut exp: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(n: u64) -> Self {
+        ModInt::new(n)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        ModInt(((u128::from(self.0) + u128::from(other.0)) % u128::from(M)) as u64)
+    }
+}
+
+impl<const M: u64> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        ModInt(((u128::from(self.0) + u128::from(M) - u128::from(other.0)) % u128::from(M)) as u64)
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        ModInt((u128::from(self.0) * u128::from(other.0) % u128::from(M)) as u64)
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Small = ModInt<7>;
+
+    #[test]
+    fn wraps_around() {
+        assert_eq!(Small::new(12).value(), 5);
+        assert_eq!((Small::new(5) + Small::new(4)).value(), 2);
+        assert_eq!((Small::new(2) - Small::new(5)).value(), 4);
+        assert_eq!((Small::new(3) * Small::new(5)).value(), 1);
+        assert_eq!(Small::new(3).pow(6).value(), 1);
+        let big: ModInt<{ u64::MAX }> = ModInt::new(u64::MAX - 1);
+        assert_eq!((big * big).value(), 1);
+        assert_eq!(ModInt::<ROSALIND_MODULUS>::new(354_224_848_179_261).to_string(), "179261");
+    }
+}
@@ -0,0 +1,163 @@
+//! Allele frequencies in randomly mating diploid populations: Hardy-Weinberg
+//! equilibrium and Wright-Fisher genetic drift.
+//!
+//! Drift probabilities are kept as natural logarithms throughout, since the
+//! chance of any particular allele count soon underflows an `f64`.
+
+use error::{Error, Result};
+use num::{binomial_ln_pmf, log_sum_exp};
+
+/// Chance that an individual carries at least one copy of a recessive
+/// allele, given the share of the population that is homozygous recessive
+/// and assuming Hardy-Weinberg equilibrium (AFRQ).
+pub fn carrier_frequency(homozygous_recessive: f64) -> Result<f64> {
+    if !(0.0..=1.0).contains(&homozygous_recessive) {
+        return Err(Error::domain(format!("A proportion must be between 0 and 1, not {}", homozygous_recessive)));
+    }
+    let dominant = 1.0 - homozygous_recessive.sqrt();
+    Ok(1.0 - dominant * dominant)
+}
+
+/// Chance that a woman carries an X-linked recessive allele without showing
+/// it, given the share of men who show it and assuming Hardy-Weinberg
+/// equilibrium (SEXL). Men have one X chromosome, so that share is the
+/// allele's frequency.
+pub fn x_linked_carrier_frequency(affected_males: f64) -> Result<f64> {
+    if !(0.0..=1.0).contains(&affected_males) {
+        return Err(Error::domain(format!("A proportion must be between 0 and 1, not {}", affected_males)));
+    }
+    Ok(2.0 * affected_males * (1.0 - affected_males))
+}
+
+/// Expected number of successes in `n` trials with each chance in `p` (EBIN).
+pub fn expected_binomial_counts(n: u64, p: &[f64]) -> Vec<f64> {
+    p.iter().map(|&p| n as f64 * p).collect()
+}
+
+/// The Wright-Fisher model: each generation's `2N` alleles are drawn with
+/// replacement from the previous generation's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrightFisher {
+    alleles: u64,
+    /// `ln_transitions[i][j]`: log chance of going from `i` copies of an
+    /// allele to `j` in one generation.
+    ln_transitions: Vec<Vec<f64>>,
+}
+
+impl WrightFisher {
+    /// Largest population modelled, keeping the `(2N + 1)²` transition table to about 32 MB.
+    pub const MAX_INDIVIDUALS: u64 = 1000;
+
+    /// A population of `individuals` diploid individuals, at most `MAX_INDIVIDUALS`.
+    pub fn new(individuals: u64) -> Result<Self> {
+        let alleles = individuals.checked_mul(2)
+            .filter(|_| individuals <= Self::MAX_INDIVIDUALS)
+            .ok_or_else(|| Error::usage(format!(
+                "Can't model drift in {} individuals, at most {}", individuals, Self::MAX_INDIVIDUALS)))?;
+        let ln_transitions = (0..=alleles)
+            .map(|i| (0..=alleles).map(|j| binomial_ln_pmf(alleles, j, i as f64 / alleles as f64)).collect())
+            .collect();
+        Ok(WrightFisher { alleles, ln_transitions })
+    }
+
+    /// Number of allele copies in the population, `2N`.
+    pub fn alleles(&self) -> u64 {
+        self.alleles
+    }
+
+    fn check_copies(&self, copies: u64) -> Result<()> {
+        if copies > self.alleles {
+            Err(Error::domain(format!("Can't have {} copies of an allele among {}", copies, self.alleles)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Log chance of each number of copies, from 0 to `2N`, in every
+    /// generation after starting with `copies`. The first item is generation 1.
+    pub fn ln_drift(&self, copies: u64) -> Result<Drift<'_>> {
+        self.check_copies(copies)?;
+        let mut ln_counts = vec![f64::NEG_INFINITY; self.alleles as usize + 1];
+        ln_counts[copies as usize] = 0.0;
+        Ok(Drift { model: self, ln_counts })
+    }
+
+    /// Chance of at least `at_least` copies after `generations`, starting with `copies` (WFMD).
+    pub fn at_least(&self, copies: u64, generations: usize, at_least: u64) -> Result<f64> {
+        let ln_counts = match generations {
+            0 => self.ln_drift(copies)?.ln_counts,
+            n => self.ln_drift(copies)?.nth(n - 1).expect("drift goes on forever"),
+        };
+        Ok(log_sum_exp(ln_counts.into_iter().skip(at_least as usize)).exp())
+    }
+
+    /// `log10` of the chance that the allele is lost in each of the first
+    /// `generations` generations, starting with `copies` (FOUN).
+    pub fn log10_loss(&self, copies: u64, generations: usize) -> Result<Vec<f64>> {
+        Ok(self.ln_drift(copies)?
+            .take(generations)
+            .map(|ln_counts| ln_counts[0] / 10f64.ln())
+            .collect())
+    }
+}
+
+/// Log chances of each allele count, one generation at a time.
+pub struct Drift<'a> {
+    model: &'a WrightFisher,
+    ln_counts: Vec<f64>,
+}
+
+impl<'a> Iterator for Drift<'a> {
+    type Item = Vec<f64>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let transitions = &self.model.ln_transitions;
+        let next: Vec<f64> = (0..transitions.len())
+            .map(|j| log_sum_exp(self.ln_counts.iter().zip(transitions).map(|(ln_p, row)| ln_p + row[j])))
+            .collect();
+        self.ln_counts = next.clone();
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn hardy_weinberg() {
+        let carriers: Vec<f64> = [0.1, 0.25, 0.5].iter().map(|&p| carrier_frequency(p).unwrap()).collect();
+        assert!(carriers.iter().zip(&[0.532, 0.75, 0.914]).all(|(&a, &b)| close(a, b)));
+        assert!(carrier_frequency(1.5).is_err());
+        assert_eq!(x_linked_carrier_frequency(0.5).unwrap(), 0.5);
+        assert!((x_linked_carrier_frequency(0.8).unwrap() - 0.32).abs() < 1e-12);
+        assert!(x_linked_carrier_frequency(-0.1).is_err());
+        assert_eq!(expected_binomial_counts(17, &[0.1, 0.2, 0.3]), vec![1.7000000000000002, 3.4000000000000004, 5.1]);
+    }
+
+    #[test]
+    fn drift() {
+        // The WFMD sample: 4 individuals, 6 dominant alleles so 2 recessive.
+        let model = WrightFisher::new(4).unwrap();
+        assert!(close(model.at_least(2, 2, 1).unwrap(), 0.772));
+        assert_eq!(model.at_least(2, 0, 2).unwrap(), 1.0);
+        assert!(model.at_least(9, 1, 1).is_err());
+
+        // The FOUN sample.
+        let expected = [[-0.463935575821, -0.999509892866], [-0.301424998891, -0.641668367342], [-0.229066698008, -0.485798552456]];
+        assert_eq!(model.log10_loss(0, 3).unwrap(), vec![0.0; 3]);
+        for (copies, column) in [1, 2].iter().zip(0..) {
+            let loss = model.log10_loss(*copies, 3).unwrap();
+            assert!(loss.iter().zip(&expected).all(|(&a, row)| close(a, row[column])), "{:?}", loss);
+        }
+
+        // A large population stays well away from underflow.
+        let big = WrightFisher::new(100).unwrap();
+        let total = log_sum_exp(big.ln_drift(100).unwrap().nth(9).unwrap());
+        assert!(total.abs() < 1e-9);
+        assert!(WrightFisher::new(1 << 63).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+//! Rabbit population recurrences, from Fibonacci's immortal pairs to
+//! age-structured (Leslie matrix) models with a lifespan.
+
+use error::{Error, Result};
+use num::{Count, Matrix};
+
+/// An age-structured population of rabbit pairs, advanced one generation
+/// at a time by a Leslie matrix.
+///
+/// Pairs are grouped by age in generations, newborns first. Each
+/// generation, every pair of age `a` produces `fecundity[a]` newborn pairs,
+/// and becomes `survival[a]` pairs of age `a + 1`: 1 to live on, 0 to die.
+/// The oldest pairs become `oldest_survival` pairs of the same age, so a 1
+/// there keeps them alive for good. Counts are always whole numbers, so
+/// the mortality schedule is too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgeModel {
+    fecundity: Vec<usize>,
+    survival: Vec<usize>,
+    oldest_survival: usize,
+}
+
+impl AgeModel {
+    /// A model with `fecundity.len()` age classes; `survival` needs one
+    /// entry fewer.
+    pub fn new(fecundity: Vec<usize>, survival: Vec<usize>, oldest_survival: usize) -> Result<AgeModel> {
+        if fecundity.is_empty() {
+            return Err(Error::usage("An age model needs at least one age class"));
+        }
+        if survival.len() + 1 != fecundity.len() {
+            return Err(Error::usage(format!("{} age classes need {} survival counts, found {}",
+                                            fecundity.len(), fecundity.len() - 1, survival.len())));
+        }
+        Ok(AgeModel { fecundity, survival, oldest_survival })
+    }
+
+    /// Fibonacci's rabbits: pairs mature after a generation, then produce
+    /// `k` pairs every generation and never die.
+    pub fn immortal(k: usize) -> AgeModel {
+        AgeModel { fecundity: vec![0, k], survival: vec![1], oldest_survival: 1 }
+    }
+
+    /// Pairs that mature after a generation, produce `k` pairs every
+    /// generation after that and die after `lifespan` generations (FIBD).
+    pub fn mortal(lifespan: usize, k: usize) -> Result<AgeModel> {
+        if lifespan == 0 {
+            return Err(Error::usage("Rabbits must live for at least one generation"));
+        }
+        let fecundity = (0..lifespan).map(|age| if age == 0 { 0 } else { k }).collect();
+        AgeModel::new(fecundity, vec![1; lifespan - 1], 0)
+    }
+
+    /// Number of age classes.
+    pub fn ages(&self) -> usize {
+        self.fecundity.len()
+    }
+
+    pub fn fecundity(&self) -> &[usize] {
+        &self.fecundity
+    }
+
+    pub fn survival(&self) -> &[usize] {
+        &self.survival
+    }
+
+    /// The Leslie matrix taking one generation's age classes to the next.
+    pub fn leslie_matrix<T: Count>(&self) -> Matrix<T> {
+        let mut matrix = Matrix::zero(self.ages());
+        for (age, &fecundity) in self.fecundity.iter().enumerate() {
+            matrix.set(0, age, T::from_usize(fecundity));
+        }
+        for (age, &survival) in self.survival.iter().enumerate() {
+            matrix.set(age + 1, age, T::from_usize(survival));
+        }
+        let oldest = self.ages() - 1;
+        let carried = matrix.get(oldest, oldest).checked_add(&T::from_usize(self.oldest_survival))
+            .expect("a single count fits");
+        matrix.set(oldest, oldest, carried);
+        matrix
+    }
+
+    /// Pairs in each age class in generation 1, when there is one newborn pair.
+    fn founders<T: Count>(&self) -> Vec<T> {
+        let mut classes = vec![T::zero(); self.ages()];
+        classes[0] = T::one();
+        classes
+    }
+
+    /// Total pairs in each generation, starting with generation 0.
+    pub fn generations<T: Count>(&self) -> Generations<T> {
+        Generations { matrix: self.leslie_matrix(), classes: Some(self.founders()), started: false }
+    }
+
+    /// Pairs in each age class after `n` generations, found by raising the
+    /// Leslie matrix to the power `n - 1`. `None` if a count can't be represented.
+    pub fn classes_after<T: Count>(&self, n: u64) -> Option<Vec<T>> {
+        match n {
+            0 => Some(vec![T::zero(); self.ages()]),
+            n => self.leslie_matrix().checked_pow(n - 1)?.checked_mul_vec(&self.founders()),
+        }
+    }
+
+    /// Total pairs after `n` generations.
+    pub fn population_after<T: Count>(&self, n: u64) -> Result<T> {
+        self.classes_after(n)
+            .and_then(|classes| total(&classes))
+            .ok_or_else(|| Error::domain(format!("Population after {} generations is too large to represent", n)))
+    }
+}
+
+fn total<T: Count>(classes: &[T]) -> Option<T> {
+    classes.iter().try_fold(T::zero(), |sum, count| sum.checked_add(count))
+}
+
+/// Total pairs of an `AgeModel`, one generation at a time.
+pub struct Generations<T: Count> {
+    matrix: Matrix<T>,
+    /// The next generation's age classes, or `None` once they overflow.
+    classes: Option<Vec<T>>,
+    /// Whether generation 0, before the first pair, has been yielded.
+    started: bool,
+}
+
+impl<T: Count> Iterator for Generations<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(T::zero());
+        }
+        let classes = self.classes.take()?;
+        let pairs = total(&classes)?;
+        self.classes = self.matrix.checked_mul_vec(&classes);
+        Some(pairs)
+    }
+}
+
+/// Rabbit pair counts, one generation at a time, starting with generation 0.
+///
+/// Each month every mature pair produces `k` new pairs, and new pairs take a
+/// month to mature. Rabbits never die: this is `AgeModel::immortal`. Counts
+/// can be any `Count`: a `usize` iterator ends once a count no longer fits,
+/// while `BigUint` and `ModInt` go on forever.
+pub struct Rabbits<T: Count = usize>(Generations<T>);
+
+impl<T: Count> Rabbits<T> {
+    pub fn new(k: usize) -> Rabbits<T> {
+        Rabbits(AgeModel::immortal(k).generations())
+    }
+}
+
+impl<T: Count> Iterator for Rabbits<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+/// Number of rabbit pairs after `n` generations, each mature pair producing `k` pairs per generation.
+pub fn calculate_rabbit_population(n: usize, k: usize) -> Result<usize> {
+    rabbit_population(n, k)
+}
+
+/// As `calculate_rabbit_population`, counting in any `Count` type.
+pub fn rabbit_population<T: Count>(n: usize, k: usize) -> Result<T> {
+    AgeModel::immortal(k).population_after(n as u64)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num::{BigUint, ModInt, ROSALIND_MODULUS};
+    
+    #[test]
+    fn test_basic_recurrence() {
+        let calculated = calculate_rabbit_population(5, 3).unwrap();
+        assert_eq!(calculated, 19)
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert_eq!(calculate_rabbit_population(92, 1).unwrap(), 7540113804746346429);
+        assert!(calculate_rabbit_population(94, 1).is_err());
+        assert!(calculate_rabbit_population(1000, 5).is_err());
+        assert_eq!(Rabbits::<usize>::new(1).count(), 94);
+    }
+
+    #[test]
+    fn exact_and_modular_populations() {
+        let exact: BigUint = rabbit_population(100, 1).unwrap();
+        assert_eq!(exact.to_string(), "354224848179261915075");
+        let modular: ModInt<ROSALIND_MODULUS> = rabbit_population(100, 1).unwrap();
+        assert_eq!(modular.value(), 915075);
+        let small: BigUint = rabbit_population(5, 3).unwrap();
+        assert_eq!(small.to_u64(), Some(19));
+    }
+
+    #[test]
+    fn mortal_rabbits() {
+        // The FIBD sample dataset.
+        let model = AgeModel::mortal(3, 1).unwrap();
+        assert_eq!(model.population_after::<usize>(6).unwrap(), 4);
+        let by_generation: Vec<usize> = model.generations().take(7).collect();
+        assert_eq!(by_generation, vec![0, 1, 1, 2, 2, 3, 4]);
+        assert_eq!(model.classes_after::<usize>(6), Some(vec![2, 1, 1]));
+
+        let big: BigUint = AgeModel::mortal(20, 1).unwrap().population_after(100).unwrap();
+        let stepped: BigUint = AgeModel::mortal(20, 1).unwrap().generations().nth(100).unwrap();
+        assert_eq!(big, stepped);
+
+        assert!(AgeModel::mortal(0, 1).is_err());
+        assert!(AgeModel::new(vec![0, 1, 1], vec![1], 0).is_err());
+    }
+
+    #[test]
+    fn age_specific_schedules() {
+        // Pairs produce two pairs in their second generation and none in
+        // their third and last.
+        let model = AgeModel::new(vec![0, 2, 0], vec![1, 1], 0).unwrap();
+        let counts: Vec<usize> = model.generations().take(6).collect();
+        assert_eq!(counts, vec![0, 1, 1, 3, 2, 6]);
+    }
+}
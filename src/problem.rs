@@ -0,0 +1,414 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use dataset;
+use error::{Error, Result};
+use io::FastaReader;
+use report::{OutputFormat, Report, Row};
+use seq::nuc::DNA;
+use seq::residue::Residue;
+use seq::rna::Rna;
+
+/// A Rosalind-style problem: how to get its input from the command line,
+/// how to solve it, and how to print the answer.
+///
+/// Implementing this is all it takes for a problem to show up as a
+/// subcommand; see `problems::registry`.
+pub trait Problem {
+    type Input;
+    type Output;
+
+    /// Subcommand name, usually the Rosalind problem id.
+    fn id(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    /// Arguments accepted on the command line. Most problems just read a file.
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::input()]
+    }
+
+    /// Arguments for solving a downloaded `rosalind_<id>.txt` dataset.
+    fn dataset_args(&self, path: &Path) -> Result<Args> {
+        dataset::default_args(&Problem::args(self), path)
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input>;
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output>;
+
+    fn format(&self, output: &Self::Output) -> String;
+
+    /// The answer as named values for JSON and TSV output. By default this is
+    /// the text answer under the name `answer`.
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("answer", self.format(output)))
+    }
+}
+
+/// The object-safe face of `Problem`, so problems with different input and
+/// output types can live in one registry.
+pub trait Solver {
+    fn id(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    fn args(&self) -> Vec<ArgSpec>;
+    fn dataset_args(&self, path: &Path) -> Result<Args>;
+    /// Parse, solve and format in one go.
+    fn run(&self, args: &Args) -> Result<String> {
+        self.run_as(args, OutputFormat::Text)
+    }
+    fn run_as(&self, args: &Args, format: OutputFormat) -> Result<String>;
+}
+
+impl<P: Problem> Solver for P {
+    fn id(&self) -> &'static str {
+        Problem::id(self)
+    }
+
+    fn description(&self) -> &'static str {
+        Problem::description(self)
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        Problem::args(self)
+    }
+
+    fn dataset_args(&self, path: &Path) -> Result<Args> {
+        Problem::dataset_args(self, path)
+    }
+
+    fn run_as(&self, args: &Args, format: OutputFormat) -> Result<String> {
+        let input = self.parse(args)?;
+        let output = self.solve(input)?;
+        Ok(match format {
+            OutputFormat::Text => self.format(&output),
+            OutputFormat::Json => self.report(&output).to_json(Problem::id(self)),
+            OutputFormat::Tsv => self.report(&output).to_tsv(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgKind {
+    /// The positional input file, always named `INPUT`.
+    Input,
+    /// Any number of positional input files, also named `INPUT`.
+    Inputs,
+    Integer,
+    Float,
+    /// Free-form text, such as a motif or a file name.
+    Text,
+    Flag,
+    Choice(&'static [&'static str]),
+}
+
+/// Description of a single command line argument, independent of any CLI library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub kind: ArgKind,
+    pub short: Option<&'static str>,
+    pub long: Option<&'static str>,
+    pub help: &'static str,
+    pub required: bool,
+}
+
+pub const INPUT: &str = "INPUT";
+
+/// The input file name that stands for standard input.
+pub const STDIN: &str = "-";
+
+impl ArgSpec {
+    pub fn input() -> Self {
+        ArgSpec {
+            name: INPUT,
+            kind: ArgKind::Input,
+            short: None,
+            long: None,
+            help: "Input file to read; '-' or no file reads standard input",
+            required: false,
+        }
+    }
+
+    pub fn inputs() -> Self {
+        ArgSpec {
+            name: INPUT,
+            kind: ArgKind::Inputs,
+            short: None,
+            long: None,
+            help: "Input files to read; '-' or no files reads standard input",
+            required: false,
+        }
+    }
+
+    /// A required non-negative integer, given as `--<name>` unless changed.
+    pub fn integer(name: &'static str, help: &'static str) -> Self {
+        ArgSpec {
+            name,
+            kind: ArgKind::Integer,
+            short: None,
+            long: Some(name),
+            help,
+            required: true,
+        }
+    }
+
+    /// A required number, given as `--<name>`.
+    pub fn float(name: &'static str, help: &'static str) -> Self {
+        ArgSpec { kind: ArgKind::Float, ..ArgSpec::integer(name, help) }
+    }
+
+    /// A required string, given as `--<name>`.
+    pub fn text(name: &'static str, help: &'static str) -> Self {
+        ArgSpec { kind: ArgKind::Text, ..ArgSpec::integer(name, help) }
+    }
+
+    pub fn flag(name: &'static str, help: &'static str) -> Self {
+        ArgSpec {
+            name,
+            kind: ArgKind::Flag,
+            short: None,
+            long: Some(name),
+            help,
+            required: false,
+        }
+    }
+
+    /// A required value from a fixed set, given as `--<name>`.
+    pub fn choice(name: &'static str, values: &'static [&'static str], help: &'static str) -> Self {
+        ArgSpec {
+            name,
+            kind: ArgKind::Choice(values),
+            short: None,
+            long: Some(name),
+            help,
+            required: true,
+        }
+    }
+
+    pub fn short(mut self, short: &'static str) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Let the argument be left out; the problem supplies a default.
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Only accept the short form.
+    pub fn short_only(mut self, short: &'static str) -> Self {
+        self.short = Some(short);
+        self.long = None;
+        self
+    }
+}
+
+/// Argument values handed to `Problem::parse`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Args {
+    values: HashMap<String, Vec<String>>,
+    flags: HashSet<String>,
+}
+
+impl Args {
+    pub fn new() -> Self {
+        Args::default()
+    }
+
+    /// Set a value, replacing any already given.
+    pub fn with_value<S: Into<String>, V: Into<String>>(mut self, name: S, value: V) -> Self {
+        self.values.insert(name.into(), vec![value.into()]);
+        self
+    }
+
+    /// Add another value to an argument that takes several.
+    pub fn push_value<S: Into<String>, V: Into<String>>(mut self, name: S, value: V) -> Self {
+        self.values.entry(name.into()).or_default().push(value.into());
+        self
+    }
+
+    pub fn with_flag<S: Into<String>>(mut self, name: S) -> Self {
+        self.flags.insert(name.into());
+        self
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values(name).first().map(String::as_str)
+    }
+
+    pub fn values(&self, name: &str) -> &[String] {
+        self.values.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn integer(&self, name: &str) -> Result<usize> {
+        let value = self.value(name)
+            .ok_or_else(|| Error::usage(format!("Missing argument '{}'", name)))?;
+        value.parse()
+            .map_err(|e| Error::usage(format!("Could not parse '{}' argument '{}' as an integer: {}", name, value, e)))
+    }
+
+    /// An integer that may be left out.
+    pub fn optional_integer(&self, name: &str) -> Result<Option<usize>> {
+        match self.value(name) {
+            Some(_) => self.integer(name).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn float(&self, name: &str) -> Result<f64> {
+        let value = self.value(name)
+            .ok_or_else(|| Error::usage(format!("Missing argument '{}'", name)))?;
+        value.parse()
+            .map_err(|e| Error::usage(format!("Could not parse '{}' argument '{}' as a number: {}", name, value, e)))
+    }
+
+    /// A number that may be left out.
+    pub fn optional_float(&self, name: &str) -> Result<Option<f64>> {
+        match self.value(name) {
+            Some(_) => self.float(name).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Open the input file, falling back to standard input for `-` or no file at all.
+    pub fn open_input(&self) -> Result<Box<dyn Read>> {
+        open(self.value(INPUT).unwrap_or(STDIN))
+    }
+
+    /// Names of every input file, with `-` standing in for standard input when there are none.
+    pub fn input_names(&self) -> Vec<&str> {
+        match self.values(INPUT) {
+            [] => vec![STDIN],
+            names => names.iter().map(String::as_str).collect(),
+        }
+    }
+
+    pub fn read_input_to_string(&self) -> Result<String> {
+        let mut buffer = String::new();
+        self.open_input()?.read_to_string(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Read the input file as a single sequence, either plain text or one FASTA record.
+    fn read_sequence_text(&self) -> Result<String> {
+        let text = self.read_input_to_string()?;
+        if !text.trim_start().starts_with('>') {
+            return Ok(text);
+        }
+        let mut records = FastaReader::new(text.as_bytes()).collect::<::std::result::Result<Vec<_>, _>>()?;
+        if records.len() != 1 {
+            return Err(Error::parse(format!("Expected a single FASTA record, found {}", records.len())));
+        }
+        Ok(records.remove(0).seq().clone())
+    }
+
+    /// Read the input file as a single DNA sequence, ignoring whitespace.
+    pub fn read_dna(&self) -> Result<Vec<DNA>> {
+        parse_sequence(&self.read_sequence_text()?, DNA::from_iupac)
+    }
+
+    /// Read the input file as FASTA, parsing each record's sequence as DNA.
+    pub fn read_dna_records(&self) -> Result<Vec<(String, Vec<DNA>)>> {
+        self.read_records()
+    }
+
+    /// Read the input file as FASTA records in any residue alphabet.
+    pub fn read_records<T: Residue>(&self) -> Result<Vec<(String, Vec<T>)>> {
+        FastaReader::new(self.open_input()?)
+            .map(|record| {
+                let record = record?;
+                Ok((record.id().clone(), parse_sequence(record.seq(), T::from_char)?))
+            })
+            .collect()
+    }
+
+    /// Read the input file as a single RNA sequence, ignoring whitespace. A DNA coding
+    /// strand is read as its transcript.
+    pub fn read_rna(&self) -> Result<Rna> {
+        parse_sequence(&self.read_sequence_text()?, Rna::coding_base_from_char).map(Rna::from)
+    }
+}
+
+/// Open a named input file, or standard input for `-`.
+pub fn open(name: &str) -> Result<Box<dyn Read>> {
+    if name == STDIN {
+        Ok(Box::new(io::stdin()))
+    } else {
+        Ok(Box::new(File::open(name)?))
+    }
+}
+
+/// Parse a plain, unlabelled sequence, reporting the first character outside the alphabet.
+pub fn parse_sequence<T, F: Fn(char) -> Option<T>>(text: &str, parse: F) -> Result<Vec<T>> {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .enumerate()
+        .map(|(idx, c)| parse(c).ok_or_else(|| Error::parse(
+            format!("Invalid residue {:?} at position {}", c, idx + 1))))
+        .collect()
+}
+
+/// Parse whitespace-separated numbers, reporting the first that isn't one.
+pub fn parse_numbers<T: FromStr>(text: &str) -> Result<Vec<T>> {
+    text.split_whitespace()
+        .map(|token| token.parse().map_err(|_| Error::parse(format!("Could not parse '{}' as a number", token))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_arguments() {
+        let args = Args::new().with_value("k", "12").with_value("m", "x").with_flag("strict");
+        assert_eq!(args.integer("k").unwrap(), 12);
+        assert!(args.integer("m").is_err());
+        assert!(args.integer("n").is_err());
+        assert!(args.flag("strict"));
+        assert!(!args.flag("protein"));
+        assert_eq!(args.optional_integer("k").unwrap(), Some(12));
+        assert_eq!(args.optional_integer("n").unwrap(), None);
+    }
+
+    #[test]
+    fn float_arguments() {
+        let args = Args::new().with_value("gc", "0.45").with_value("rate", "high");
+        assert_eq!(args.float("gc").unwrap(), 0.45);
+        assert!(args.float("rate").is_err());
+        assert_eq!(args.optional_float("missing").unwrap(), None);
+    }
+
+    #[test]
+    fn several_inputs() {
+        let args = Args::new().push_value(INPUT, "a.fa").push_value(INPUT, "b.fq");
+        assert_eq!(args.value(INPUT), Some("a.fa"));
+        assert_eq!(args.clone().with_value(INPUT, "c.fa").input_names(), vec!["c.fa"]);
+        assert_eq!(args.input_names(), vec!["a.fa", "b.fq"]);
+        assert_eq!(Args::new().input_names(), vec![STDIN]);
+    }
+
+    #[test]
+    fn input_file_is_optional() {
+        assert!(!ArgSpec::input().required);
+        assert!(Args::new().with_value(INPUT, "no/such/file").open_input().is_err());
+    }
+
+    #[test]
+    fn sequence_parsing() {
+        assert_eq!(parse_sequence("AC\nGT\n", DNA::from_iupac).unwrap(), vec![DNA::A, DNA::C, DNA::G, DNA::T]);
+        let err = parse_sequence("ACGU", DNA::from_iupac).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid residue 'U' at position 4");
+        assert_eq!(parse_numbers::<f64>(" 0.1 0.25\n0.5\n").unwrap(), vec![0.1, 0.25, 0.5]);
+        assert!(parse_numbers::<usize>("1 2 x").is_err());
+    }
+}
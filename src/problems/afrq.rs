@@ -0,0 +1,38 @@
+use popgen::carrier_frequency;
+use error::Result;
+use problem::{parse_numbers, Args, Problem};
+use report::{Report, Row};
+
+pub struct Afrq;
+
+impl Problem for Afrq {
+    type Input = Vec<f64>;
+    /// Each proportion of homozygous recessive individuals with its carrier frequency.
+    type Output = Vec<(f64, f64)>;
+
+    fn id(&self) -> &'static str {
+        "afrq"
+    }
+
+    fn description(&self) -> &'static str {
+        "Counting Disease Carriers"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        parse_numbers(&args.read_input_to_string()?)
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.into_iter().map(|p| carrier_frequency(p).map(|carriers| (p, carriers))).collect()
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter().map(|(_, carriers)| carriers.to_string()).collect::<Vec<_>>().join(" ")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter().fold(Report::new(), |report, &(p, carriers)| {
+            report.row(Row::new().field("homozygous_recessive", p).field("carrier_frequency", carriers))
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use std::io::BufRead;
+use std::io::BufReader;
+use error::{Error, Result};
+use problem::{Args, Problem};
+use report::{Report, Row};
+use stats::n_x;
+
+pub struct Asmq;
+
+impl Problem for Asmq {
+    type Input = Vec<usize>;
+    type Output = (usize, usize);
+
+    fn id(&self) -> &'static str {
+        "asmq"
+    }
+
+    fn description(&self) -> &'static str {
+        "Assess assembly quality with N50 and N75"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let mut lengths = Vec::new();
+        for line in BufReader::new(args.open_input()?).lines() {
+            let line = line?;
+            let contig = line.trim();
+            if !contig.is_empty() {
+                lengths.push(contig.len());
+            }
+        }
+        Ok(lengths)
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        match (n_x(&input, 50), n_x(&input, 75)) {
+            (Some(n50), Some(n75)) => Ok((n50, n75)),
+            _ => Err(Error::domain("No contigs to assess")),
+        }
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format!("{} {}", output.0, output.1)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field_with_unit("N50", output.0, "bp").field_with_unit("N75", output.1, "bp"))
+    }
+}
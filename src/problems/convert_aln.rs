@@ -0,0 +1,77 @@
+use std::io;
+use msa::{Alignment, Format, FORMAT_NAMES};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+use seq::residue::Residue;
+use seq::translation::TranslatedCodon;
+
+pub enum AnyAlignment {
+    Dna(Alignment<DNA>),
+    Protein(Alignment<TranslatedCodon>),
+}
+
+fn write_alignment<T: Residue>(alignment: &Alignment<T>, format: Format) -> String {
+    let mut out = Vec::new();
+    alignment.write(&mut out, format).expect("Writing to memory cannot fail");
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn parse_format(args: &Args, name: &str) -> io::Result<Format> {
+    args.value(name)
+        .unwrap_or_default()
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+pub struct ConvertAln;
+
+impl Problem for ConvertAln {
+    type Input = (AnyAlignment, Format);
+    type Output = String;
+
+    fn id(&self) -> &'static str {
+        "convert-aln"
+    }
+
+    fn description(&self) -> &'static str {
+        "Convert multiple sequence alignments between formats"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::choice("from", FORMAT_NAMES, "Format of the input alignment"),
+            ArgSpec::choice("to", FORMAT_NAMES, "Format to write the alignment in"),
+            ArgSpec::flag("protein", "Read residues as amino acids rather than nucleotides"),
+            ArgSpec::input(),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let from = parse_format(args, "from")?;
+        let to = parse_format(args, "to")?;
+        let input = args.open_input()?;
+        let alignment = if args.flag("protein") {
+            AnyAlignment::Protein(Alignment::read(input, from)?)
+        } else {
+            AnyAlignment::Dna(Alignment::read(input, from)?)
+        };
+        Ok((alignment, to))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(match input {
+            (AnyAlignment::Dna(alignment), to) => write_alignment(&alignment, to),
+            (AnyAlignment::Protein(alignment), to) => write_alignment(&alignment, to),
+        })
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.trim_end().to_owned()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("alignment", self.format(output)))
+    }
+}
@@ -0,0 +1,41 @@
+use error::Result;
+use problem::{Args, Problem};
+use report::{Report, Row};
+use seq::composition::{count_occurrences, BaseCounts};
+use seq::nuc::DNA;
+
+pub struct Dna;
+
+impl Problem for Dna {
+    type Input = Vec<DNA>;
+    type Output = BaseCounts;
+
+    fn id(&self) -> &'static str {
+        "dna"
+    }
+
+    fn description(&self) -> &'static str {
+        "Count nucleotide occurrence"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        args.read_dna()
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(count_occurrences(&input))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        [DNA::A, DNA::C, DNA::G, DNA::T].iter()
+            .map(|&base| output.count(base).to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        let row = [DNA::A, DNA::C, DNA::G, DNA::T].iter()
+            .fold(Row::new(), |row, &base| row.field(char::from(base).to_string(), output.count(base)));
+        Report::single(row)
+    }
+}
@@ -0,0 +1,47 @@
+use popgen::expected_binomial_counts;
+use error::{Error, Result};
+use problem::{parse_numbers, Args, Problem};
+use report::{Report, Row};
+
+pub struct Ebin;
+
+impl Problem for Ebin {
+    /// The number of trials and the chance of success in each.
+    type Input = (u64, Vec<f64>);
+    /// Each chance with its expected number of successes.
+    type Output = Vec<(f64, f64)>;
+
+    fn id(&self) -> &'static str {
+        "ebin"
+    }
+
+    fn description(&self) -> &'static str {
+        "Wright-Fisher's Expected Behavior"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let text = args.read_input_to_string()?;
+        let mut tokens = text.splitn(2, char::is_whitespace);
+        let n = tokens.next().unwrap_or("").parse()
+            .map_err(|_| Error::parse("Expected the number of trials before the chances"))?;
+        Ok((n, parse_numbers(tokens.next().unwrap_or(""))?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (n, p) = input;
+        if let Some(bad) = p.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+            return Err(Error::domain(format!("A chance must be between 0 and 1, not {}", bad)));
+        }
+        Ok(p.iter().cloned().zip(expected_binomial_counts(n, &p)).collect())
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter().map(|(_, expected)| expected.to_string()).collect::<Vec<_>>().join(" ")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter().fold(Report::new(), |report, &(p, expected)| {
+            report.row(Row::new().field("probability", p).field("expected", expected))
+        })
+    }
+}
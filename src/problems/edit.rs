@@ -0,0 +1,40 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{alphabet_arg, read_pair, Aligned, Pair};
+use report::{Report, Row};
+
+pub struct Edit;
+
+impl Problem for Edit {
+    type Input = Pair;
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "edit"
+    }
+
+    fn description(&self) -> &'static str {
+        "Edit Distance"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![alphabet_arg(), ArgSpec::input()]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        read_pair(args, "protein")
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.align(&Scoring::edit_distance(), Mode::Global)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.edit_distance.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field_with_unit("distance", output.edit_distance, "edits"))
+    }
+}
@@ -0,0 +1,44 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{alphabet_arg, read_pair, Aligned, Pair};
+use report::{Report, Row};
+
+pub struct Edta;
+
+impl Problem for Edta {
+    type Input = Pair;
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "edta"
+    }
+
+    fn description(&self) -> &'static str {
+        "Edit Distance Alignment"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![alphabet_arg(), ArgSpec::input()]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        read_pair(args, "protein")
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.align(&Scoring::edit_distance(), Mode::Global)
+    }
+
+    /// The distance, then the two aligned rows.
+    fn format(&self, output: &Self::Output) -> String {
+        format!("{}\n{}\n{}", output.edit_distance, output.rows.0, output.rows.1)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new()
+            .field_with_unit("distance", output.edit_distance, "edits")
+            .field("first", output.rows.0.as_str())
+            .field("second", output.rows.1.as_str()))
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use population::rabbit_population;
+use error::Result;
+use num::{BigUint, ModInt, ROSALIND_MODULUS};
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row, Value};
+
+pub struct Fib;
+
+/// The population, either in full or modulo `ROSALIND_MODULUS`.
+pub enum Population {
+    Exact(BigUint),
+    Modular(ModInt<ROSALIND_MODULUS>),
+}
+
+impl Population {
+    pub fn report(&self) -> Report {
+        let value = match self {
+            Population::Exact(count) => count.to_u64().map_or_else(|| Value::from(count.to_string()), Value::from),
+            Population::Modular(count) => Value::from(count.value()),
+        };
+        Report::single(Row::new().field_with_unit("population", value, "pairs"))
+    }
+}
+
+impl fmt::Display for Population {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Population::Exact(count) => count.fmt(f),
+            Population::Modular(count) => count.fmt(f),
+        }
+    }
+}
+
+impl Problem for Fib {
+    type Input = (usize, usize, bool);
+    type Output = Population;
+
+    fn id(&self) -> &'static str {
+        "fib"
+    }
+
+    fn description(&self) -> &'static str {
+        "Population size simulation"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("generations", "Number of rabbit generations to simulate").short("g"),
+            ArgSpec::integer("fecundity", "Number of rabbit pairs are produced per rabbit pair per generation").short("f"),
+            ArgSpec::flag("modular", "Print the population modulo 1,000,000 instead of in full").short("m"),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((args.integer("generations")?, args.integer("fecundity")?, args.flag("modular")))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (n, k, modular) = input;
+        if modular {
+            rabbit_population(n, k).map(Population::Modular)
+        } else {
+            rabbit_population(n, k).map(Population::Exact)
+        }
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.report()
+    }
+}
@@ -0,0 +1,79 @@
+use population::AgeModel;
+use error::{Error, Result};
+use problem::{ArgSpec, Args, Problem};
+use problems::fib::Population;
+use report::Report;
+
+pub struct Fibd;
+
+/// A comma-separated list of counts, one per age class.
+fn counts(args: &Args, name: &str, len: usize) -> Result<Option<Vec<usize>>> {
+    let spec = match args.value(name) {
+        Some(spec) => spec,
+        None => return Ok(None),
+    };
+    let counts = spec.split(',')
+        .map(|count| count.trim().parse()
+            .map_err(|_| Error::usage(format!("Could not parse '{}' in --{} as a count", count, name))))
+        .collect::<Result<Vec<usize>>>()?;
+    if counts.len() == len {
+        Ok(Some(counts))
+    } else {
+        Err(Error::usage(format!("--{} needs {} comma-separated counts, found {}", name, len, counts.len())))
+    }
+}
+
+impl Problem for Fibd {
+    /// The model, the number of generations and whether to count modulo 1,000,000.
+    type Input = (AgeModel, u64, bool);
+    type Output = Population;
+
+    fn id(&self) -> &'static str {
+        "fibd"
+    }
+
+    fn description(&self) -> &'static str {
+        "Mortal Fibonacci Rabbits"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("generations", "Number of rabbit generations to simulate").short("g"),
+            ArgSpec::integer("lifespan", "Number of generations each rabbit pair lives").short("l"),
+            ArgSpec::text("fecundity", "Pairs produced by a pair of each age, newborns first, such as 0,1,1 (default 0 then 1s)").optional(),
+            ArgSpec::text("survival", "Pairs that survive to the next age from each age but the last, such as 1,1 (default all 1s)").optional(),
+            ArgSpec::flag("modular", "Print the population modulo 1,000,000 instead of in full").short("m"),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let lifespan = args.integer("lifespan")?;
+        let default = AgeModel::mortal(lifespan, 1)?;
+        let model = match (counts(args, "fecundity", lifespan)?, counts(args, "survival", lifespan - 1)?) {
+            (None, None) => default,
+            (fecundity, survival) => AgeModel::new(
+                fecundity.unwrap_or_else(|| default.fecundity().to_vec()),
+                survival.unwrap_or_else(|| default.survival().to_vec()),
+                0,
+            )?,
+        };
+        Ok((model, args.integer("generations")? as u64, args.flag("modular")))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (model, n, modular) = input;
+        if modular {
+            model.population_after(n).map(Population::Modular)
+        } else {
+            model.population_after(n).map(Population::Exact)
+        }
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.report()
+    }
+}
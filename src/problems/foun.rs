@@ -0,0 +1,59 @@
+use popgen::WrightFisher;
+use error::{Error, Result};
+use problem::{parse_numbers, Args, Problem};
+use report::{Report, Row};
+
+pub struct Foun;
+
+impl Problem for Foun {
+    /// Individuals, generations and each starting number of recessive copies.
+    type Input = (u64, usize, Vec<u64>);
+    /// The starting copies, and a row per generation of log10 chances of losing them all.
+    type Output = (Vec<u64>, Vec<Vec<f64>>);
+
+    fn id(&self) -> &'static str {
+        "foun"
+    }
+
+    fn description(&self) -> &'static str {
+        "The Founder Effect and Genetic Drift"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let numbers: Vec<u64> = parse_numbers(&args.read_input_to_string()?)?;
+        match numbers.split_first() {
+            Some((&individuals, rest)) if !rest.is_empty() => Ok((individuals, rest[0] as usize, rest[1..].to_vec())),
+            _ => Err(Error::parse("Expected the population size and number of generations before the allele counts")),
+        }
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (individuals, generations, copies) = input;
+        let model = WrightFisher::new(individuals)?;
+        let columns = copies.iter()
+            .map(|&copies| model.log10_loss(copies, generations))
+            .collect::<Result<Vec<_>>>()?;
+        let rows = (0..generations).map(|g| columns.iter().map(|column| column[g]).collect()).collect();
+        Ok((copies, rows))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.1.iter()
+            .map(|row| row.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        let (ref copies, ref rows) = *output;
+        let mut report = Report::new();
+        for (generation, row) in rows.iter().enumerate() {
+            for (&copies, &log10_loss) in copies.iter().zip(row) {
+                report = report.row(Row::with_id(format!("generation_{}", generation + 1))
+                    .field("recessive_copies", copies)
+                    .field("log10_loss", log10_loss));
+            }
+        }
+        report
+    }
+}
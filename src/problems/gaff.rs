@@ -0,0 +1,63 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{alphabet_arg, format_alignment, matrix_args, read_matrix, read_pair, read_score, report_alignment,
+                     Aligned, Pair};
+use report::Report;
+
+const DEFAULT_MATRIX: &str = "blosum62";
+const DEFAULT_OPEN: usize = 11;
+const DEFAULT_EXTEND: usize = 1;
+
+pub struct Gaff;
+
+/// Substitution matrix and affine gap arguments, read with `read_affine`;
+/// BLOSUM62 with gaps opening at 11 and extending at 1 by default.
+pub fn affine_args() -> Vec<ArgSpec> {
+    let mut args = matrix_args();
+    args.push(ArgSpec::integer("open", "Penalty for the first position of a gap (default 11)").optional());
+    args.push(ArgSpec::integer("extend", "Penalty for each later position of a gap (default 1)").optional());
+    args
+}
+
+pub fn read_affine(args: &Args) -> Result<Scoring> {
+    let open = read_score(args, "open", DEFAULT_OPEN)?;
+    let extend = read_score(args, "extend", DEFAULT_EXTEND)?;
+    Ok(Scoring::affine(read_matrix(args, DEFAULT_MATRIX)?, open, extend))
+}
+
+impl Problem for Gaff {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "gaff"
+    }
+
+    fn description(&self) -> &'static str {
+        "Global Alignment with Scoring Matrix and Affine Gap Penalty"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        let mut args = affine_args();
+        args.push(alphabet_arg());
+        args.push(ArgSpec::input());
+        args
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((read_pair(args, "protein")?, read_affine(args)?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Global)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format_alignment(output)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
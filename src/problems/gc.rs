@@ -0,0 +1,60 @@
+use io::RefReader;
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+
+pub struct Gc;
+
+impl Problem for Gc {
+    /// The id and GC fraction of every record, in file order.
+    type Input = Vec<(String, f64)>;
+    type Output = Option<(String, f64)>;
+
+    fn id(&self) -> &'static str {
+        "gc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Identify highest GC content sequence from fasta file"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::flag("strict", "Reject malformed FASTA instead of skipping it"),
+            ArgSpec::input(),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let mut reader = RefReader::new(args.open_input()?).strict(args.flag("strict"));
+        let mut contents = Vec::new();
+        while let Some(record) = reader.next() {
+            let record = record?;
+            contents.push((String::from_utf8_lossy(record.id()).into_owned(), record.gc_percent()));
+        }
+        Ok(contents)
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let mut best: Option<(String, f64)> = None;
+        for (id, gc_percent) in input {
+            if best.as_ref().is_none_or(|b| gc_percent > b.1) {
+                best = Some((id, gc_percent));
+            }
+        }
+        Ok(best)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        match output {
+            Some((name, gc_percent)) => format!("{}\n{}", name, gc_percent * 100.0),
+            None => String::new(),
+        }
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter().fold(Report::new(), |report, (name, gc_percent)| {
+            report.row(Row::with_id(name.as_str()).field_with_unit("gc_content", gc_percent * 100.0, "%"))
+        })
+    }
+}
@@ -0,0 +1,168 @@
+use std::convert::TryFrom;
+use align::{self, Mode, PairwiseAlignment, Scoring, SubstitutionMatrix};
+use error::{Error, Result};
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+use seq::residue::Residue;
+use seq::translation::TranslatedCodon;
+
+const ALPHABET_NAMES: &[&str] = &["protein", "dna"];
+const DEFAULT_MATRIX: &str = "blosum62";
+const DEFAULT_GAP: usize = 5;
+
+pub struct Glob;
+
+/// Two sequences to align, in the alphabet chosen with `--alphabet`.
+pub enum Pair {
+    Protein(Vec<TranslatedCodon>, Vec<TranslatedCodon>),
+    Dna(Vec<DNA>, Vec<DNA>),
+}
+
+/// An alignment with its rows as text, whichever alphabet it was in.
+pub struct Aligned {
+    pub score: i32,
+    pub edit_distance: usize,
+    pub rows: (String, String),
+    /// The aligned parts of each sequence, without gaps.
+    pub substrings: (String, String),
+}
+
+impl<T: Residue> From<PairwiseAlignment<T>> for Aligned {
+    fn from(alignment: PairwiseAlignment<T>) -> Self {
+        let ungapped = |row: &[T]| row.iter().filter(|r| !r.is_gap()).map(|r| r.to_char()).collect();
+        Aligned {
+            score: alignment.score,
+            edit_distance: alignment.edit_distance(),
+            rows: alignment.row_strings(),
+            substrings: (ungapped(&alignment.rows.0), ungapped(&alignment.rows.1)),
+        }
+    }
+}
+
+impl Pair {
+    pub fn align(&self, scoring: &Scoring, mode: Mode) -> Result<Aligned> {
+        match self {
+            Pair::Protein(a, b) => align::align(a, b, scoring, mode).map(Aligned::from),
+            Pair::Dna(a, b) => align::align(a, b, scoring, mode).map(Aligned::from),
+        }
+    }
+}
+
+/// The score, then the two aligned rows.
+pub fn format_alignment(output: &Aligned) -> String {
+    format!("{}\n{}\n{}", output.score, output.rows.0, output.rows.1)
+}
+
+/// The score, then the aligned part of each sequence, as local alignment
+/// problems ask for.
+pub fn format_substrings(output: &Aligned) -> String {
+    format!("{}\n{}\n{}", output.score, output.substrings.0, output.substrings.1)
+}
+
+pub fn report_alignment(output: &Aligned) -> Report {
+    Report::single(Row::new()
+        .field("score", output.score)
+        .field("first", output.rows.0.as_str())
+        .field("second", output.rows.1.as_str()))
+}
+
+fn two_records<T: Residue>(args: &Args) -> Result<(Vec<T>, Vec<T>)> {
+    let mut records = args.read_records()?.into_iter().map(|(_, seq)| seq);
+    match (records.next(), records.next(), records.next()) {
+        (Some(first), Some(second), None) => Ok((first, second)),
+        _ => Err(Error::parse("Expected exactly two FASTA records")),
+    }
+}
+
+/// `--alphabet`, for reading the input with `read_pair`.
+pub fn alphabet_arg() -> ArgSpec {
+    ArgSpec::choice("alphabet", ALPHABET_NAMES, "Read the sequences as protein or DNA").optional()
+}
+
+/// The input's two FASTA records, in the alphabet chosen with `--alphabet`
+/// or else `default`.
+pub fn read_pair(args: &Args, default: &str) -> Result<Pair> {
+    match args.value("alphabet").unwrap_or(default) {
+        "dna" => two_records(args).map(|(a, b)| Pair::Dna(a, b)),
+        _ => two_records(args).map(|(a, b)| Pair::Protein(a, b)),
+    }
+}
+
+/// Arguments choosing a substitution matrix, read with `read_matrix`.
+pub fn matrix_args() -> Vec<ArgSpec> {
+    vec![
+        ArgSpec::text("matrix", "Substitution matrix: blosum62, pam250, dna or an NCBI format matrix file").optional(),
+        ArgSpec::integer("match", "Score for a match, with DNA match/mismatch scoring").optional(),
+        ArgSpec::integer("mismatch", "Penalty for a mismatch, with DNA match/mismatch scoring").optional(),
+    ]
+}
+
+/// The substitution matrix named by `--matrix`, `default` if there isn't
+/// one, or DNA scoring when `--match` or `--mismatch` is given.
+pub fn read_matrix(args: &Args, default: &str) -> Result<SubstitutionMatrix> {
+    if args.value("match").is_none() && args.value("mismatch").is_none() {
+        return SubstitutionMatrix::named(args.value("matrix").unwrap_or(default));
+    }
+    read_dna_matrix(args, 1, 1)
+}
+
+/// The score or penalty given as `--<name>`, or else `default`, refusing
+/// anything above `align::MAX_SCORE`.
+pub fn read_score(args: &Args, name: &str, default: usize) -> Result<i32> {
+    let score = args.optional_integer(name)?.unwrap_or(default);
+    i32::try_from(score).ok()
+        .filter(|&score| score <= align::MAX_SCORE)
+        .ok_or_else(|| Error::usage(format!("'{}' must be at most {}, not {}", name, align::MAX_SCORE, score)))
+}
+
+/// DNA match/mismatch scoring from `--match` and `--mismatch`, or else
+/// `matches` and `mismatches`.
+pub fn read_dna_matrix(args: &Args, matches: usize, mismatches: usize) -> Result<SubstitutionMatrix> {
+    match args.value("matrix") {
+        None | Some("dna") => {
+            let matches = read_score(args, "match", matches)?;
+            let mismatches = read_score(args, "mismatch", mismatches)?;
+            Ok(SubstitutionMatrix::dna(matches, -mismatches))
+        }
+        Some(_) => Err(Error::usage("--match and --mismatch only apply to --matrix dna")),
+    }
+}
+
+impl Problem for Glob {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "glob"
+    }
+
+    fn description(&self) -> &'static str {
+        "Global Alignment with Scoring Matrix"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        let mut args = matrix_args();
+        args.push(ArgSpec::integer("gap", "Penalty for each gap position (default 5)").optional());
+        args.push(alphabet_arg());
+        args.push(ArgSpec::input());
+        args
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let gap = read_score(args, "gap", DEFAULT_GAP)?;
+        Ok((read_pair(args, "protein")?, Scoring::linear(read_matrix(args, DEFAULT_MATRIX)?, gap)))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Global)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.score.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
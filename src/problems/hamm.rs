@@ -0,0 +1,138 @@
+use distance::{ambiguous_hamming_distance, hamming_distance};
+use error::{Error, Result};
+use io::FastaReader;
+use iter::{all_pairs, Tuples};
+use problem::{parse_sequence, ArgSpec, Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+
+pub struct Hamm;
+
+/// Sequences to compare, and which of them to compare.
+pub struct Sequences {
+    /// FASTA ids, line numbers for plain sequences compared all against all,
+    /// or `None` for plain sequences compared in consecutive pairs.
+    ids: Option<Vec<String>>,
+    seqs: Vec<Vec<DNA>>,
+    all_pairs: bool,
+    ambiguous: bool,
+}
+
+/// The distance between two of the sequences.
+pub struct Comparison {
+    /// The two sequences' ids, if they have any.
+    ids: Option<(String, String)>,
+    distance: usize,
+}
+
+impl Sequences {
+    /// Plain sequences are compared in consecutive pairs, and FASTA records
+    /// first against each of the others, unless every pair is wanted.
+    fn pairs(&self) -> Result<Vec<(usize, usize)>> {
+        let indices: Vec<usize> = (0..self.seqs.len()).collect();
+        if self.all_pairs {
+            Ok(all_pairs(&indices).map(|((i, _), (j, _))| (i, j)).collect())
+        } else if self.ids.is_some() {
+            Ok((1..self.seqs.len()).map(|j| (0, j)).collect())
+        } else {
+            let mut tuples = indices.into_iter().tuples();
+            let pairs = tuples.by_ref().collect();
+            match tuples.remainder() {
+                Some(_) => Err(Error::parse(format!("Sequences are compared in pairs, but there are {}", self.seqs.len()))),
+                None => Ok(pairs),
+            }
+        }
+    }
+}
+
+fn read_plain(text: &str) -> Result<Vec<Vec<DNA>>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_sequence(line, DNA::from_iupac))
+        .collect()
+}
+
+impl Problem for Hamm {
+    type Input = Sequences;
+    type Output = Vec<Comparison>;
+
+    fn id(&self) -> &'static str {
+        "hamm"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calculate hamming distance between sequences"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::flag("ambiguous", "Let ambiguity codes such as N and R match any base they could stand for"),
+            ArgSpec::flag("all-pairs", "Compare every pair of sequences"),
+            ArgSpec::input(),
+        ]
+    }
+
+    /// FASTA, told apart by its leading `>`, or one sequence per line.
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let text = args.read_input_to_string()?;
+        let (ids, seqs) = if text.trim_start().starts_with('>') {
+            let mut ids = Vec::new();
+            let mut seqs = Vec::new();
+            for record in FastaReader::new(text.as_bytes()) {
+                let record = record?;
+                seqs.push(parse_sequence(record.seq(), DNA::from_iupac)?);
+                ids.push(record.id().clone());
+            }
+            (Some(ids), seqs)
+        } else {
+            (None, read_plain(&text)?)
+        };
+        let all_pairs = args.flag("all-pairs");
+        // Plain sequences are named by line when every pair is printed.
+        let ids = match ids {
+            None if all_pairs => Some((1..=seqs.len()).map(|n| n.to_string()).collect()),
+            ids => ids,
+        };
+        Ok(Sequences { ids, seqs, all_pairs, ambiguous: args.flag("ambiguous") })
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.pairs()?.into_iter()
+            .map(|(i, j)| {
+                let (first, second) = (&input.seqs[i], &input.seqs[j]);
+                let distance = if input.ambiguous {
+                    ambiguous_hamming_distance(first, second)?
+                } else {
+                    hamming_distance(first, second)?
+                };
+                let ids = input.ids.as_ref().map(|ids| (ids[i].clone(), ids[j].clone()));
+                Ok(Comparison { ids, distance })
+            })
+            .collect()
+    }
+
+    /// Bare distances for consecutive plain sequences, or else both ids (line
+    /// numbers for plain sequences) then the distance.
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter()
+            .map(|comparison| match comparison.ids {
+                Some((ref first, ref second)) => format!("{} {} {}", first, second, comparison.distance),
+                None => comparison.distance.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter()
+            .enumerate()
+            .fold(Report::new(), |report, (idx, comparison)| {
+                let row = Row::with_id(format!("pair_{}", idx + 1));
+                let row = match comparison.ids {
+                    Some((ref first, ref second)) => row.field("first", first.as_str()).field("second", second.as_str()),
+                    None => row,
+                };
+                report.row(row.field_with_unit("distance", comparison.distance, "substitutions"))
+            })
+    }
+}
@@ -0,0 +1,54 @@
+use mendel::expected_dominant_offspring;
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+
+/// The couple arguments, in the order Rosalind lists them.
+const COUPLE_ARGS: [&str; 6] = ["AA-AA", "AA-Aa", "AA-aa", "Aa-Aa", "Aa-aa", "aa-aa"];
+const OFFSPRING_PER_COUPLE: usize = 2;
+
+pub struct Iev;
+
+impl Problem for Iev {
+    type Input = [usize; 6];
+    type Output = f64;
+
+    fn id(&self) -> &'static str {
+        "iev"
+    }
+
+    fn description(&self) -> &'static str {
+        "Calculating Expected Offspring"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("AA-AA", "Number of couples where both parents are AA"),
+            ArgSpec::integer("AA-Aa", "Number of AA-Aa couples"),
+            ArgSpec::integer("AA-aa", "Number of AA-aa couples"),
+            ArgSpec::integer("Aa-Aa", "Number of Aa-Aa couples"),
+            ArgSpec::integer("Aa-aa", "Number of Aa-aa couples"),
+            ArgSpec::integer("aa-aa", "Number of aa-aa couples"),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let mut couples = [0; 6];
+        for (count, name) in couples.iter_mut().zip(&COUPLE_ARGS) {
+            *count = args.integer(name)?;
+        }
+        Ok(couples)
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(expected_dominant_offspring(input, OFFSPRING_PER_COUPLE))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field_with_unit("expected_dominant", *output, "offspring"))
+    }
+}
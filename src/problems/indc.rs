@@ -0,0 +1,41 @@
+use mendel::shared_chromosomes_log10;
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+
+pub struct Indc;
+
+impl Problem for Indc {
+    type Input = u64;
+    type Output = Vec<f64>;
+
+    fn id(&self) -> &'static str {
+        "indc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Independent Segregation of Chromosomes"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::integer("pairs", "Number of chromosome pairs in the diploid organism").short("n")]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok(args.integer("pairs")? as u64)
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        shared_chromosomes_log10(input)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" ")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter().enumerate().fold(Report::new(), |report, (idx, &log10_p)| {
+            report.row(Row::new().field("at_least_shared", idx + 1).field("log10_probability", log10_p))
+        })
+    }
+}
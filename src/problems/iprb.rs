@@ -0,0 +1,44 @@
+use mendel::dominant_phenotype_probability;
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+
+pub struct Iprb;
+
+impl Problem for Iprb {
+    type Input = (usize, usize, usize);
+    type Output = f64;
+
+    fn id(&self) -> &'static str {
+        "iprb"
+    }
+
+    fn description(&self) -> &'static str {
+        "Intro to Mendelian Inheritance"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("dominant", "Number of homozygous dominant individuals").short_only("k"),
+            ArgSpec::integer("heterozygous", "Number of heterozygous individuals").short_only("m"),
+            ArgSpec::integer("recessive", "Number of homozygous recessive individuals").short_only("n"),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((args.integer("dominant")?, args.integer("heterozygous")?, args.integer("recessive")?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (k, m, n) = input;
+        dominant_phenotype_probability(k, m, n)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("dominant_probability", *output))
+    }
+}
@@ -0,0 +1,55 @@
+use std::ops::Range;
+use align::approximate_occurrences;
+use error::{Error, Result};
+use problem::{parse_sequence, Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+
+pub struct Ksim;
+
+impl Problem for Ksim {
+    /// The largest edit distance allowed, the motif and the genome.
+    type Input = (usize, Vec<DNA>, Vec<DNA>);
+    type Output = Vec<Range<usize>>;
+
+    fn id(&self) -> &'static str {
+        "ksim"
+    }
+
+    fn description(&self) -> &'static str {
+        "Finding All Similar Motifs"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let text = args.read_input_to_string()?;
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        match tokens[..] {
+            [distance, motif, genome] => {
+                let distance = distance.parse().map_err(|_| Error::parse(format!("Could not parse '{}' as a number", distance)))?;
+                Ok((distance, parse_sequence(motif, DNA::from_iupac)?, parse_sequence(genome, DNA::from_iupac)?))
+            }
+            _ => Err(Error::parse("Expected the edit distance, the motif and the genome")),
+        }
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (distance, ref motif, ref genome) = input;
+        Ok(approximate_occurrences(motif, genome, distance))
+    }
+
+    /// Each occurrence's 1-based start and its length.
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter()
+            .map(|range| format!("{} {}", range.start + 1, range.len()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter()
+            .enumerate()
+            .fold(Report::new(), |report, (idx, range)| report.row(Row::with_id(format!("occurrence_{}", idx + 1))
+                .field("start", range.start + 1)
+                .field_with_unit("length", range.len(), "bp")))
+    }
+}
@@ -0,0 +1,44 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::gaff::{affine_args, read_affine};
+use problems::glob::{alphabet_arg, format_substrings, read_pair, report_alignment, Aligned, Pair};
+use report::Report;
+
+pub struct Laff;
+
+impl Problem for Laff {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "laff"
+    }
+
+    fn description(&self) -> &'static str {
+        "Local Alignment with Affine Gap Penalty"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        let mut args = affine_args();
+        args.push(alphabet_arg());
+        args.push(ArgSpec::input());
+        args
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((read_pair(args, "protein")?, read_affine(args)?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Local)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format_substrings(output)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
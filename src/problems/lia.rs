@@ -0,0 +1,52 @@
+use mendel::{independent_alleles, parse_genotypes, Genotype};
+use error::{Error, Result};
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+
+const DEFAULT_GENOTYPE: &str = "AaBb";
+
+pub struct Lia;
+
+impl Problem for Lia {
+    /// Generations, the least number of matching organisms, and the genotype.
+    type Input = (u32, u64, Vec<Genotype>);
+    type Output = f64;
+
+    fn id(&self) -> &'static str {
+        "lia"
+    }
+
+    fn description(&self) -> &'static str {
+        "Independent Alleles"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("generations", "Number of generations after the founder").short("k"),
+            ArgSpec::integer("at-least", "Least number of organisms in the last generation with the genotype").short("n"),
+            ArgSpec::text("genotype", "Genotype of the founder and of every mate (default AaBb)").optional(),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let generations = args.integer("generations")?;
+        if generations >= 64 {
+            return Err(Error::usage(format!("{} generations is too many; 2^generations organisms must be countable", generations)));
+        }
+        let genotype = parse_genotypes(args.value("genotype").unwrap_or(DEFAULT_GENOTYPE))?;
+        Ok((generations as u32, args.integer("at-least")? as u64, genotype))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (generations, at_least, genotype) = input;
+        independent_alleles(&genotype, generations, at_least)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("probability", *output))
+    }
+}
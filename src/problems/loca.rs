@@ -0,0 +1,49 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{alphabet_arg, format_substrings, matrix_args, read_matrix, read_pair, read_score,
+                     report_alignment, Aligned, Pair};
+use report::Report;
+
+const DEFAULT_MATRIX: &str = "pam250";
+const DEFAULT_GAP: usize = 5;
+
+pub struct Loca;
+
+impl Problem for Loca {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "loca"
+    }
+
+    fn description(&self) -> &'static str {
+        "Local Alignment with Scoring Matrix"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        let mut args = matrix_args();
+        args.push(ArgSpec::integer("gap", "Penalty for each gap position (default 5)").optional());
+        args.push(alphabet_arg());
+        args.push(ArgSpec::input());
+        args
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let gap = read_score(args, "gap", DEFAULT_GAP)?;
+        Ok((read_pair(args, "protein")?, Scoring::linear(read_matrix(args, DEFAULT_MATRIX)?, gap)))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Local)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format_substrings(output)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
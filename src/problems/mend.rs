@@ -0,0 +1,51 @@
+use mendel::{pedigree_genotype, Organism, GENOTYPES};
+use error::Result;
+use problem::{Args, Problem};
+use report::{Report, Row};
+use tree::Tree;
+
+pub struct Mend;
+
+/// The letter conventionally used for a locus: `A`, then `B` and so on.
+fn locus_letter(locus: usize) -> char {
+    (b'A' + (locus % 26) as u8) as char
+}
+
+impl Problem for Mend {
+    type Input = Tree;
+    type Output = Organism;
+
+    fn id(&self) -> &'static str {
+        "mend"
+    }
+
+    fn description(&self) -> &'static str {
+        "Inferring Genotype from a Pedigree"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok(args.read_input_to_string()?.parse()?)
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        pedigree_genotype(&input)
+    }
+
+    /// One line per locus, giving the chance of each genotype from homozygous dominant to recessive.
+    fn format(&self, output: &Self::Output) -> String {
+        output.loci().iter()
+            .map(|locus| GENOTYPES.iter().map(|&g| locus.probability(g).to_string()).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.loci().iter().enumerate().fold(Report::new(), |report, (idx, locus)| {
+            let letter = locus_letter(idx);
+            let row = GENOTYPES.iter().fold(Row::with_id(letter.to_string()), |row, &g| {
+                row.field(g.to_letters(letter), locus.probability(g))
+            });
+            report.row(row)
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use problem::Solver;
+
+/// Declares each problem module and lists its `Problem` in the registry, so
+/// adding a problem only needs a new file and a line here.
+macro_rules! problems {
+    ($($module:ident => $problem:ident),* $(,)*) => {
+        $(pub mod $module;)*
+
+        /// Every problem the crate can solve, in the order they are listed on the command line.
+        pub fn registry() -> Vec<Box<dyn Solver>> {
+            vec![$(Box::new($module::$problem)),*]
+        }
+    };
+}
+
+problems! {
+    dna => Dna,
+    rna => Rna,
+    revc => Revc,
+    fib => Fib,
+    fibd => Fibd,
+    gc => Gc,
+    hamm => Hamm,
+    tran => Tran,
+    pdst => Pdst,
+    iprb => Iprb,
+    iev => Iev,
+    lia => Lia,
+    mend => Mend,
+    afrq => Afrq,
+    wfmd => Wfmd,
+    foun => Foun,
+    ebin => Ebin,
+    sexl => Sexl,
+    indc => Indc,
+    prot => Prot,
+    edit => Edit,
+    edta => Edta,
+    glob => Glob,
+    gaff => Gaff,
+    loca => Loca,
+    laff => Laff,
+    smgb => Smgb,
+    oap => Oap,
+    sims => Sims,
+    ksim => Ksim,
+    convert_aln => ConvertAln,
+    asmq => Asmq,
+    stats => Stats,
+    simulate => Simulate,
+}
+
+/// Look a problem up by its subcommand name.
+pub fn find(id: &str) -> Option<Box<dyn Solver>> {
+    registry().into_iter().find(|solver| solver.id() == id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn ids_are_unique() {
+        let ids: HashSet<_> = registry().iter().map(|solver| solver.id()).collect();
+        assert_eq!(ids.len(), registry().len());
+        assert!(find("dna").is_some());
+        assert!(find("nope").is_none());
+    }
+}
@@ -0,0 +1,42 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{format_alignment, read_pair, report_alignment, Aligned, Pair};
+use problems::smgb::{dna_args, read_dna_scoring};
+use report::Report;
+
+pub struct Oap;
+
+impl Problem for Oap {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "oap"
+    }
+
+    fn description(&self) -> &'static str {
+        "Overlap Alignment"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        dna_args("Penalty for each gap position (default 2)")
+    }
+
+    /// Mismatches cost 2 by default, as Rosalind scores overlaps.
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((read_pair(args, "dna")?, read_dna_scoring(args, 2, 2)?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Overlap)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format_alignment(output)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
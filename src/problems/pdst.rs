@@ -0,0 +1,61 @@
+use distance::{DistanceMatrix, Model, MODEL_NAMES};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+
+/// Decimal places Rosalind's sample answer uses.
+const DECIMALS: usize = 5;
+
+pub struct Pdst;
+
+impl Problem for Pdst {
+    /// The records, the distance model and whether to write PHYLIP.
+    type Input = (Vec<(String, Vec<DNA>)>, Model, bool);
+    type Output = (DistanceMatrix, bool);
+
+    fn id(&self) -> &'static str {
+        "pdst"
+    }
+
+    fn description(&self) -> &'static str {
+        "Creating a Distance Matrix"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::choice("model", MODEL_NAMES, "How to measure distance (default p)").optional(),
+            ArgSpec::flag("phylip", "Write the matrix in PHYLIP format, with sequence names"),
+            ArgSpec::input(),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let model = args.value("model").unwrap_or("p").parse()?;
+        Ok((args.read_dna_records()?, model, args.flag("phylip")))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (records, model, phylip) = input;
+        let matrix = DistanceMatrix::build(&records, |a, b| model.distance(a, b))?;
+        Ok((matrix, phylip))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        match *output {
+            (ref matrix, true) => matrix.to_phylip(DECIMALS),
+            (ref matrix, false) => matrix.to_table(DECIMALS),
+        }
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        let matrix = &output.0;
+        let mut report = Report::new();
+        for (i, label) in matrix.labels().iter().enumerate() {
+            for (j, other) in matrix.labels().iter().enumerate().skip(i + 1) {
+                report = report.row(Row::with_id(format!("{}-{}", label, other)).field("distance", matrix.get(i, j)));
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,40 @@
+use error::Result;
+use problem::{Args, Problem};
+use report::{Report, Row};
+use seq::rna::Rna;
+use seq::translation::{translate, TranslatedCodon};
+use seq::translation::ncbi_translation_tables::STANDARD;
+
+pub struct Prot;
+
+impl Problem for Prot {
+    type Input = Rna;
+    type Output = Vec<TranslatedCodon>;
+
+    fn id(&self) -> &'static str {
+        "prot"
+    }
+
+    fn description(&self) -> &'static str {
+        "Translate RNA or DNA into protein"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        args.read_rna()
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(translate(input.bases(), &STANDARD))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter()
+            .filter(|&&residue| residue != TranslatedCodon::Stop)
+            .map(|&residue| char::from(residue))
+            .collect()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("protein", self.format(output)))
+    }
+}
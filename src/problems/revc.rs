@@ -0,0 +1,35 @@
+use error::Result;
+use problem::{Args, Problem};
+use report::{Report, Row};
+use seq::nuc::{reverse_complement, DNA};
+
+pub struct Revc;
+
+impl Problem for Revc {
+    type Input = Vec<DNA>;
+    type Output = Vec<DNA>;
+
+    fn id(&self) -> &'static str {
+        "revc"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reverse complement a DNA sequence"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        args.read_dna()
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(reverse_complement(&input))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.iter().map(|&base| char::from(base)).collect()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("reverse_complement", self.format(output)))
+    }
+}
@@ -0,0 +1,36 @@
+use error::Result;
+use problem::{Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+use seq::rna;
+
+pub struct Rna;
+
+impl Problem for Rna {
+    type Input = Vec<DNA>;
+    type Output = rna::Rna;
+
+    fn id(&self) -> &'static str {
+        "rna"
+    }
+
+    fn description(&self) -> &'static str {
+        "Transcribe DNA into RNA"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        args.read_dna()
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        Ok(rna::Rna::transcribe(&input))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("rna", self.format(output)))
+    }
+}
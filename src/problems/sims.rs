@@ -0,0 +1,42 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{format_alignment, read_pair, report_alignment, Aligned, Pair};
+use problems::smgb::{dna_args, read_dna_scoring};
+use report::Report;
+
+pub struct Sims;
+
+impl Problem for Sims {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "sims"
+    }
+
+    fn description(&self) -> &'static str {
+        "Finding a Motif with Modifications"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        dna_args("Penalty for each gap position (default 1)")
+    }
+
+    /// The first record is the text, and the second the motif fitted into it.
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((read_pair(args, "dna")?, read_dna_scoring(args, 1, 1)?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Fitting)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format_alignment(output)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
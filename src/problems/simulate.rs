@@ -0,0 +1,167 @@
+use error::{Error, Result};
+use io::FastaReader;
+use problem::{open, ArgSpec, Args, Problem};
+use simulate::{Alphabet, Background, Composition, ErrorModel, MarkovModel, Motif, ReadSimulation, SimulatedRead,
+               SimulatedSeq, Simulation, ALPHABET_NAMES};
+
+const DEFAULT_SEED: usize = 1;
+const DEFAULT_COUNT: usize = 1;
+const DEFAULT_LENGTH: usize = 1000;
+const DEFAULT_ORDER: usize = 2;
+const DEFAULT_READ_LENGTH: usize = 100;
+
+pub struct Simulate;
+
+pub enum Simulated {
+    Sequences(Vec<SimulatedSeq>),
+    /// Reads, along with the sequences they were drawn from.
+    Reads { seqs: Vec<SimulatedSeq>, reads: Vec<SimulatedRead>, fastq: bool },
+}
+
+/// A rate argument, which must lie between 0 and 1.
+fn rate(args: &Args, name: &str) -> Result<f64> {
+    let rate = args.optional_float(name)?.unwrap_or(0.0);
+    if (0.0..=1.0).contains(&rate) {
+        Ok(rate)
+    } else {
+        Err(Error::usage(format!("--{} must be between 0 and 1, not {}", name, rate)))
+    }
+}
+
+fn background(args: &Args, alphabet: Alphabet) -> Result<Background> {
+    let choices = [args.value("gc").is_some(), args.value("composition").is_some(), args.value("train").is_some()];
+    if choices.iter().filter(|&&chosen| chosen).count() > 1 {
+        return Err(Error::usage("Use only one of --gc, --composition and --train"));
+    }
+
+    if let Some(gc) = args.optional_float("gc")? {
+        return Ok(Background::Composition(Composition::gc_content(alphabet, gc)?));
+    }
+    if let Some(spec) = args.value("composition") {
+        return Ok(Background::Composition(Composition::parse(alphabet, spec)?));
+    }
+    if let Some(path) = args.value("train") {
+        let order = args.optional_integer("order")?.unwrap_or(DEFAULT_ORDER);
+        if MarkovModel::table_size(alphabet, order).is_none() {
+            return Err(Error::usage(format!(
+                "A Markov model of order {} over this alphabet needs more than {} transition weights",
+                order, MarkovModel::MAX_TABLE_SIZE)));
+        }
+        let records = FastaReader::new(open(path)?).collect::<::std::result::Result<Vec<_>, _>>()?;
+        let model = MarkovModel::train(alphabet, order, records.iter().map(|r| r.seq().as_bytes()));
+        return Ok(Background::Markov(model));
+    }
+    Ok(Background::Composition(Composition::uniform(alphabet)))
+}
+
+fn describe_seq(seq: &SimulatedSeq) -> String {
+    match seq.motif_start {
+        Some(start) => format!(">{} motif_start={}", seq.id, start + 1),
+        None => format!(">{}", seq.id),
+    }
+}
+
+fn describe_read(n: usize, read: &SimulatedRead, seqs: &[SimulatedSeq]) -> String {
+    format!("read{} source={} start={} errors={}", n, seqs[read.source].id, read.start + 1, read.errors)
+}
+
+impl Problem for Simulate {
+    /// The simulation, and whether to write reads as FASTQ.
+    type Input = (Simulation, bool);
+    type Output = Simulated;
+
+    fn id(&self) -> &'static str {
+        "simulate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generate reproducible random sequences, motifs and reads"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("seed", "Random seed; the same seed gives the same output (default 1)").short("s").optional(),
+            ArgSpec::choice("alphabet", ALPHABET_NAMES, "Kind of sequence to generate (default dna)").optional(),
+            ArgSpec::integer("count", "Number of sequences (default 1)").short("n").optional(),
+            ArgSpec::integer("length", "Length of each sequence (default 1000)").short("l").optional(),
+            ArgSpec::float("gc", "GC fraction of nucleotide sequences, from 0 to 1").optional(),
+            ArgSpec::text("composition", "Letter weights, such as A=0.3,C=0.2,G=0.2,T=0.3").optional(),
+            ArgSpec::text("train", "FASTA file to train a Markov background model on").optional(),
+            ArgSpec::integer("order", "Order of the Markov model (default 2)").optional(),
+            ArgSpec::text("motif", "Motif to plant once in every sequence").optional(),
+            ArgSpec::float("motif-rate", "Chance of mutating each position of a planted motif").optional(),
+            ArgSpec::integer("reads", "Write this many reads drawn from the sequences instead of the sequences").optional(),
+            ArgSpec::integer("read-length", "Length of each read (default 100)").optional(),
+            ArgSpec::float("substitution", "Per-base substitution rate in reads").optional(),
+            ArgSpec::float("insertion", "Per-base insertion rate in reads").optional(),
+            ArgSpec::float("deletion", "Per-base deletion rate in reads").optional(),
+            ArgSpec::flag("fastq", "Write reads as FASTQ with simulated qualities"),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let alphabet: Alphabet = args.value("alphabet").unwrap_or("dna").parse()?;
+        let motif = match args.value("motif") {
+            Some(motif) => {
+                if let Some(c) = motif.bytes().find(|&b| alphabet.index(b).is_none()) {
+                    return Err(Error::usage(format!("Motif letter '{}' is not in the {:?} alphabet", char::from(c), alphabet)));
+                }
+                Some(Motif { seq: motif.to_ascii_uppercase().into_bytes(), mutation_rate: rate(args, "motif-rate")? })
+            }
+            None => None,
+        };
+        let reads = match args.optional_integer("reads")? {
+            Some(count) => Some(ReadSimulation {
+                count,
+                length: args.optional_integer("read-length")?.unwrap_or(DEFAULT_READ_LENGTH),
+                errors: ErrorModel {
+                    substitution: rate(args, "substitution")?,
+                    insertion: rate(args, "insertion")?,
+                    deletion: rate(args, "deletion")?,
+                },
+            }),
+            None if args.flag("fastq") => return Err(Error::usage("--fastq needs --reads")),
+            None => None,
+        };
+
+        let simulation = Simulation {
+            seed: args.optional_integer("seed")?.unwrap_or(DEFAULT_SEED) as u64,
+            count: args.optional_integer("count")?.unwrap_or(DEFAULT_COUNT),
+            length: args.optional_integer("length")?.unwrap_or(DEFAULT_LENGTH),
+            background: background(args, alphabet)?,
+            motif,
+            reads,
+        };
+        Ok((simulation, args.flag("fastq")))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (simulation, fastq) = input;
+        let (seqs, reads) = simulation.run();
+        match simulation.reads {
+            None => Ok(Simulated::Sequences(seqs)),
+            Some(ref wanted) if reads.len() < wanted.count => Err(Error::domain("Can't draw reads from empty sequences")),
+            Some(_) => Ok(Simulated::Reads { seqs, reads, fastq }),
+        }
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        let mut lines = Vec::new();
+        match output {
+            Simulated::Sequences(seqs) => for seq in seqs {
+                lines.push(describe_seq(seq));
+                lines.push(String::from_utf8_lossy(&seq.seq).into_owned());
+            },
+            Simulated::Reads { seqs, reads, fastq } => for (n, read) in reads.iter().enumerate() {
+                let description = describe_read(n + 1, read, seqs);
+                let seq = String::from_utf8_lossy(&read.seq).into_owned();
+                if *fastq {
+                    lines.extend(vec![format!("@{}", description), seq, "+".to_owned(), read.qual_string()]);
+                } else {
+                    lines.extend(vec![format!(">{}", description), seq]);
+                }
+            },
+        }
+        lines.join("\n")
+    }
+}
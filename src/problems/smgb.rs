@@ -0,0 +1,60 @@
+use align::{Mode, Scoring};
+use error::Result;
+use problem::{ArgSpec, Args, Problem};
+use problems::glob::{alphabet_arg, format_alignment, matrix_args, read_dna_matrix, read_matrix, read_pair, read_score,
+                     report_alignment, Aligned, Pair};
+use report::Report;
+
+pub struct Smgb;
+
+/// Arguments for the DNA alignment problems, read with `read_dna_scoring`.
+pub fn dna_args(gap_help: &'static str) -> Vec<ArgSpec> {
+    let mut args = matrix_args();
+    args.push(ArgSpec::integer("gap", gap_help).optional());
+    args.push(alphabet_arg());
+    args.push(ArgSpec::input());
+    args
+}
+
+/// Match/mismatch scoring, scoring a match 1 and penalising a mismatch
+/// `mismatches` and each gap position `gap` unless told otherwise.
+pub fn read_dna_scoring(args: &Args, mismatches: usize, gap: usize) -> Result<Scoring> {
+    let matrix = match args.value("matrix") {
+        None | Some("dna") => read_dna_matrix(args, 1, mismatches)?,
+        Some(_) => read_matrix(args, "dna")?,
+    };
+    Ok(Scoring::linear(matrix, read_score(args, "gap", gap)?))
+}
+
+impl Problem for Smgb {
+    type Input = (Pair, Scoring);
+    type Output = Aligned;
+
+    fn id(&self) -> &'static str {
+        "smgb"
+    }
+
+    fn description(&self) -> &'static str {
+        "Semiglobal Alignment"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        dna_args("Penalty for each gap position, other than at either end (default 1)")
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((read_pair(args, "dna")?, read_dna_scoring(args, 1, 1)?))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.0.align(&input.1, Mode::Semiglobal)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        format_alignment(output)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        report_alignment(output)
+    }
+}
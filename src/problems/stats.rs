@@ -0,0 +1,147 @@
+use std::io::Read;
+use error::Result;
+use problem::{open, ArgSpec, Args, Problem};
+use report::{Report, Row};
+use stats::{read_stats, SeqFormat, SeqStats};
+
+pub struct Stats;
+
+fn format_name(format: SeqFormat) -> &'static str {
+    match format {
+        SeqFormat::Fasta => "FASTA",
+        SeqFormat::Fastq => "FASTQ",
+    }
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_owned(), |v| v.to_string())
+}
+
+/// Lay out cells in columns, left-aligning the first two and right-aligning the numbers.
+fn align(rows: &[Vec<String>]) -> String {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| rows.iter().filter_map(|row| row.get(col)).map(String::len).max().unwrap_or(0))
+        .collect();
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .enumerate()
+                .map(|(col, (cell, &width))| if col < 2 {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                })
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Problem for Stats {
+    type Input = Vec<(String, Box<dyn Read>)>;
+    type Output = Vec<(String, SeqStats)>;
+
+    fn id(&self) -> &'static str {
+        "stats"
+    }
+
+    fn description(&self) -> &'static str {
+        "Summarise the sequences in FASTA or FASTQ files"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![ArgSpec::inputs()]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        args.input_names()
+            .into_iter()
+            .map(|name| Ok((name.to_owned(), open(name)?)))
+            .collect()
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        input.into_iter()
+            .map(|(name, reader)| Ok((name, read_stats(reader)?)))
+            .collect()
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        let with_quality = output.iter().any(|(_, stats)| stats.quality.is_some());
+        let mut header: Vec<String> = ["file", "format", "num_seqs", "sum_len", "min_len", "avg_len", "max_len",
+            "N50", "N90", "L50", "GC(%)", "ambiguous"].iter().map(|s| s.to_string()).collect();
+        if with_quality {
+            header.extend(["AvgQual", "Q20(%)", "Q30(%)"].iter().map(|s| s.to_string()));
+        }
+
+        let mut rows = vec![header];
+        for (name, stats) in output {
+            let mut row = vec![
+                name.clone(),
+                format_name(stats.format).to_owned(),
+                stats.count().to_string(),
+                stats.total_length().to_string(),
+                optional(stats.min_length()),
+                optional(stats.mean_length().map(|mean| format!("{:.1}", mean))),
+                optional(stats.max_length()),
+                optional(stats.n_x(50)),
+                optional(stats.n_x(90)),
+                optional(stats.l_x(50)),
+                optional(stats.gc_percent().map(|gc| format!("{:.2}", gc))),
+                stats.ambiguous.to_string(),
+            ];
+            if with_quality {
+                match stats.quality {
+                    Some(ref quality) if quality.bases > 0 => row.extend(vec![
+                        format!("{:.2}", quality.mean()),
+                        format!("{:.2}", quality.q20_percent()),
+                        format!("{:.2}", quality.q30_percent()),
+                    ]),
+                    _ => row.extend(vec!["-".to_owned(); 3]),
+                }
+            }
+            rows.push(row);
+        }
+        align(&rows)
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        output.iter().fold(Report::new(), |report, (name, stats)| {
+            let bases = |row: Row, field: &str, length: Option<usize>| match length {
+                Some(length) => row.field_with_unit(field, length, "bp"),
+                None => row,
+            };
+            let mut row = Row::with_id(name.as_str())
+                .field("format", format_name(stats.format))
+                .field("num_seqs", stats.count())
+                .field_with_unit("sum_len", stats.total_length(), "bp");
+            row = bases(row, "min_len", stats.min_length());
+            if let Some(mean) = stats.mean_length() {
+                row = row.field_with_unit("avg_len", mean, "bp");
+            }
+            row = bases(row, "max_len", stats.max_length());
+            row = bases(row, "N50", stats.n_x(50));
+            row = bases(row, "N90", stats.n_x(90));
+            if let Some(l50) = stats.l_x(50) {
+                row = row.field("L50", l50);
+            }
+            if let Some(gc) = stats.gc_percent() {
+                row = row.field_with_unit("gc", gc, "%");
+            }
+            row = row.field_with_unit("ambiguous", stats.ambiguous, "bp");
+            if let Some(ref quality) = stats.quality {
+                if quality.bases > 0 {
+                    row = row.field("avg_qual", quality.mean())
+                        .field_with_unit("q20", quality.q20_percent(), "%")
+                        .field_with_unit("q30", quality.q30_percent(), "%");
+                }
+            }
+            report.row(row)
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use distance::Substitutions;
+use error::{Error, Result};
+use problem::{Args, Problem};
+use report::{Report, Row};
+use seq::nuc::DNA;
+
+pub struct Tran;
+
+impl Problem for Tran {
+    type Input = (Vec<DNA>, Vec<DNA>);
+    type Output = (Substitutions, f64);
+
+    fn id(&self) -> &'static str {
+        "tran"
+    }
+
+    fn description(&self) -> &'static str {
+        "Transitions and Transversions"
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        let mut records = args.read_dna_records()?.into_iter().map(|(_, seq)| seq);
+        match (records.next(), records.next(), records.next()) {
+            (Some(first), Some(second), None) => Ok((first, second)),
+            _ => Err(Error::parse("Expected exactly two FASTA records")),
+        }
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let substitutions = Substitutions::count(&input.0, &input.1)?;
+        Ok((substitutions, substitutions.ratio()?))
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.1.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        let (substitutions, ratio) = *output;
+        Report::single(Row::new()
+            .field("transitions", substitutions.transitions)
+            .field("transversions", substitutions.transversions)
+            .field("ratio", ratio))
+    }
+}
@@ -0,0 +1,50 @@
+use popgen::WrightFisher;
+use error::{Error, Result};
+use problem::{ArgSpec, Args, Problem};
+use report::{Report, Row};
+
+pub struct Wfmd;
+
+impl Problem for Wfmd {
+    /// Individuals, dominant allele copies, generations and the least number of recessive copies.
+    type Input = (u64, u64, usize, u64);
+    type Output = f64;
+
+    fn id(&self) -> &'static str {
+        "wfmd"
+    }
+
+    fn description(&self) -> &'static str {
+        "The Wright-Fisher Model of Genetic Drift"
+    }
+
+    fn args(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::integer("individuals", "Number of diploid individuals in the population").short("N"),
+            ArgSpec::integer("dominant", "Copies of the dominant allele to start with").short("m"),
+            ArgSpec::integer("generations", "Number of generations of drift").short("g"),
+            ArgSpec::integer("at-least", "Least number of recessive copies after the last generation").short("k"),
+        ]
+    }
+
+    fn parse(&self, args: &Args) -> Result<Self::Input> {
+        Ok((args.integer("individuals")? as u64, args.integer("dominant")? as u64,
+            args.integer("generations")?, args.integer("at-least")? as u64))
+    }
+
+    fn solve(&self, input: Self::Input) -> Result<Self::Output> {
+        let (individuals, dominant, generations, at_least) = input;
+        let model = WrightFisher::new(individuals)?;
+        let recessive = model.alleles().checked_sub(dominant)
+            .ok_or_else(|| Error::domain(format!("Can't have {} dominant copies among {}", dominant, model.alleles())))?;
+        model.at_least(recessive, generations, at_least)
+    }
+
+    fn format(&self, output: &Self::Output) -> String {
+        output.to_string()
+    }
+
+    fn report(&self, output: &Self::Output) -> Report {
+        Report::single(Row::new().field("probability", *output))
+    }
+}
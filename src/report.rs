@@ -0,0 +1,241 @@
+//! Machine-readable answers, for when the text Rosalind expects isn't enough.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// How to print a problem's answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// The answer exactly as Rosalind expects it.
+    #[default]
+    Text,
+    Json,
+    /// One line per value: `id`, `field`, `value` and `unit`, with a header.
+    Tsv,
+}
+
+pub const OUTPUT_FORMAT_NAMES: &[&str] = &["text", "json", "tsv"];
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!("Unknown output format '{}', expected one of {}", s, OUTPUT_FORMAT_NAMES.join(", "))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(u64),
+    /// An integer that may be negative, such as an alignment score.
+    Signed(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<usize> for Value {
+    fn from(n: usize) -> Self {
+        Value::Integer(n as u64)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(n: u64) -> Self {
+        Value::Integer(n)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(n: i32) -> Self {
+        Value::Signed(i64::from(n))
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Signed(n)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(x: f64) -> Self {
+        Value::Float(x)
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl<'a> From<&'a str> for Value {
+    fn from(s: &'a str) -> Self {
+        Value::Text(s.to_owned())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Integer(n) => write!(f, "{}", n),
+            Value::Signed(n) => write!(f, "{}", n),
+            Value::Float(x) => write!(f, "{}", x),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A named value, with its unit if it has one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub value: Value,
+    pub unit: Option<&'static str>,
+}
+
+/// The values belonging to one record, or to the whole input when `id` is `None`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Row {
+    pub id: Option<String>,
+    pub fields: Vec<Field>,
+}
+
+impl Row {
+    pub fn new() -> Self {
+        Row::default()
+    }
+
+    pub fn with_id<S: Into<String>>(id: S) -> Self {
+        Row { id: Some(id.into()), fields: Vec::new() }
+    }
+
+    pub fn field<S: Into<String>, V: Into<Value>>(mut self, name: S, value: V) -> Self {
+        self.fields.push(Field { name: name.into(), value: value.into(), unit: None });
+        self
+    }
+
+    pub fn field_with_unit<S: Into<String>, V: Into<Value>>(mut self, name: S, value: V, unit: &'static str) -> Self {
+        self.fields.push(Field { name: name.into(), value: value.into(), unit: Some(unit) });
+        self
+    }
+}
+
+/// A problem's answer as rows of named values.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Report {
+    pub rows: Vec<Row>,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Report::default()
+    }
+
+    /// A report holding a single row without an id.
+    pub fn single(row: Row) -> Self {
+        Report { rows: vec![row] }
+    }
+
+    pub fn row(mut self, row: Row) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// `{"problem": ..., "results": [{"id": ..., "values": {...}, "units": {...}}]}`
+    pub fn to_json(&self, problem: &str) -> String {
+        let results: Vec<String> = self.rows.iter().map(|row| {
+            let id = row.id.as_ref().map_or_else(|| "null".to_owned(), |id| json_string(id));
+            let values: Vec<String> = row.fields.iter()
+                .map(|field| format!("{}: {}", json_string(&field.name), json_value(&field.value)))
+                .collect();
+            let units: Vec<String> = row.fields.iter()
+                .filter_map(|field| field.unit.map(|unit| format!("{}: {}", json_string(&field.name), json_string(unit))))
+                .collect();
+            format!("{{\"id\": {}, \"values\": {{{}}}, \"units\": {{{}}}}}", id, values.join(", "), units.join(", "))
+        }).collect();
+        format!("{{\"problem\": {}, \"results\": [{}]}}", json_string(problem), results.join(", "))
+    }
+
+    pub fn to_tsv(&self) -> String {
+        let mut lines = vec!["id\tfield\tvalue\tunit".to_owned()];
+        for row in &self.rows {
+            for field in &row.fields {
+                lines.push(format!("{}\t{}\t{}\t{}",
+                    tsv_cell(row.id.as_deref().unwrap_or_default()),
+                    tsv_cell(&field.name),
+                    tsv_cell(&field.value.to_string()),
+                    field.unit.unwrap_or_default()));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Integer(n) => n.to_string(),
+        Value::Signed(n) => n.to_string(),
+        Value::Float(x) if x.is_finite() => format!("{:?}", x),
+        Value::Float(_) => "null".to_owned(),
+        Value::Text(s) => json_string(s),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Tabs and newlines would break the table, so they become spaces.
+fn tsv_cell(s: &str) -> String {
+    s.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gc_report() -> Report {
+        Report::new()
+            .row(Row::with_id("Rosalind_0808").field_with_unit("gc_content", 60.5, "%"))
+            .row(Row::new().field("note", "tab\there \"quoted\""))
+    }
+
+    #[test]
+    fn json_output() {
+        assert_eq!(gc_report().to_json("gc"), concat!(
+            r#"{"problem": "gc", "results": ["#,
+            r#"{"id": "Rosalind_0808", "values": {"gc_content": 60.5}, "units": {"gc_content": "%"}}, "#,
+            r#"{"id": null, "values": {"note": "tab\there \"quoted\""}, "units": {}}]}"#));
+        assert_eq!(json_value(&Value::Float(1.0)), "1.0");
+        assert_eq!(json_value(&Value::from(-8)), "-8");
+        assert_eq!(json_value(&Value::Float(f64::NAN)), "null");
+    }
+
+    #[test]
+    fn tsv_output() {
+        assert_eq!(gc_report().to_tsv(),
+            "id\tfield\tvalue\tunit\nRosalind_0808\tgc_content\t60.5\t%\n\tnote\ttab here \"quoted\"\t");
+        assert_eq!("tsv".parse(), Ok(OutputFormat::Tsv));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+}
@@ -9,16 +9,16 @@ pub struct Codon(DNA, DNA, DNA);
 impl Codon {
     pub fn get_translation_index(&self) -> Option<usize> {
         match self {
-            Codon(DNA::T, DNA::T, DNA::T) => Some(00),
-            Codon(DNA::T, DNA::T, DNA::C) => Some(01),
-            Codon(DNA::T, DNA::T, DNA::A) => Some(02),
-            Codon(DNA::T, DNA::T, DNA::G) => Some(03),
-            Codon(DNA::T, DNA::C, DNA::T) => Some(04),
-            Codon(DNA::T, DNA::C, DNA::C) => Some(05),
-            Codon(DNA::T, DNA::C, DNA::A) => Some(06),
-            Codon(DNA::T, DNA::C, DNA::G) => Some(07),
-            Codon(DNA::T, DNA::A, DNA::T) => Some(08),
-            Codon(DNA::T, DNA::A, DNA::C) => Some(09),
+            Codon(DNA::T, DNA::T, DNA::T) => Some(0),
+            Codon(DNA::T, DNA::T, DNA::C) => Some(1),
+            Codon(DNA::T, DNA::T, DNA::A) => Some(2),
+            Codon(DNA::T, DNA::T, DNA::G) => Some(3),
+            Codon(DNA::T, DNA::C, DNA::T) => Some(4),
+            Codon(DNA::T, DNA::C, DNA::C) => Some(5),
+            Codon(DNA::T, DNA::C, DNA::A) => Some(6),
+            Codon(DNA::T, DNA::C, DNA::G) => Some(7),
+            Codon(DNA::T, DNA::A, DNA::T) => Some(8),
+            Codon(DNA::T, DNA::A, DNA::C) => Some(9),
             Codon(DNA::T, DNA::A, DNA::A) => Some(10),
             Codon(DNA::T, DNA::A, DNA::G) => Some(11),
             Codon(DNA::T, DNA::G, DNA::T) => Some(12),
@@ -96,6 +96,17 @@ impl TryFrom<&[char]> for Codon {
     }
 }
 
+impl TryFrom<&[DNA]> for Codon {
+    type Error = ();
+
+    fn try_from(value: &[DNA]) -> Result<Self, Self::Error> {
+        match value {
+            [a, b, c] => Ok(Codon(*a, *b, *c)),
+            _ => Err(()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,52 @@
+use seq::nuc::DNA;
+
+/// How often each nucleotide code occurs in a sequence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BaseCounts([usize; 16]);
+
+impl BaseCounts {
+    pub fn count(&self, base: DNA) -> usize {
+        self.0[base as usize]
+    }
+
+    /// Number of bases counted, gaps excluded.
+    pub fn total(&self) -> usize {
+        self.0[1..].iter().sum()
+    }
+
+    /// Fraction of unambiguous `C` or `G` among all counted bases.
+    pub fn gc_fraction(&self) -> f64 {
+        (self.count(DNA::C) + self.count(DNA::G)) as f64 / self.total() as f64
+    }
+}
+
+/// Count every nucleotide code in `seq`.
+pub fn count_occurrences(seq: &[DNA]) -> BaseCounts {
+    let mut counts = BaseCounts::default();
+    for &base in seq {
+        counts.0[base as usize] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //noinspection ALL
+    #[test]
+    fn count_basic_occurrences() {
+        let input: Vec<DNA> = "AGCTTTTCATTCTGACTGCAACGGGCAATATGTCTCTGTGTGGATTAAAAAAAGAGTGTCTGATAGCAGC"
+            .chars()
+            .map(DNA::from)
+            .collect();
+        let counts = count_occurrences(&input);
+
+        assert_eq!(counts.count(DNA::A), 20);
+        assert_eq!(counts.count(DNA::C), 12);
+        assert_eq!(counts.count(DNA::G), 17);
+        assert_eq!(counts.count(DNA::T), 21);
+        assert_eq!(counts.count(DNA::N), 0);
+        assert_eq!(counts.total(), 70);
+    }
+}
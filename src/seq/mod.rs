@@ -1,3 +1,7 @@
 pub mod nuc;
 pub mod codon;
-pub mod translation;
\ No newline at end of file
+pub mod translation;
+pub mod residue;
+pub mod packed;
+pub mod rna;
+pub mod composition;
@@ -1,5 +1,4 @@
 use std::ops::{BitAnd, BitOr, BitXor};
-use std::convert::TryFrom;
 
 ///
 /// We can probably encode everything in four bits like so:
@@ -33,7 +32,7 @@ use std::convert::TryFrom;
 ///
 /// etc..
 ///
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
 pub enum DNA {
     // G A C T
     T = 0b0001, // 0 0 0 1
@@ -102,6 +101,29 @@ impl From<&char> for DNA {
     }
 }
 
+impl From<DNA> for char {
+    fn from(base: DNA) -> Self {
+        match base {
+            DNA::A => 'A',
+            DNA::C => 'C',
+            DNA::G => 'G',
+            DNA::T => 'T',
+            DNA::Y => 'Y',
+            DNA::W => 'W',
+            DNA::M => 'M',
+            DNA::K => 'K',
+            DNA::S => 'S',
+            DNA::R => 'R',
+            DNA::H => 'H',
+            DNA::B => 'B',
+            DNA::D => 'D',
+            DNA::V => 'V',
+            DNA::N => 'N',
+            DNA::Gap => '-',
+        }
+    }
+}
+
 impl BitAnd for DNA {
     type Output = Self;
 
@@ -131,11 +153,45 @@ impl DNA {
         let bits = self as u8;
         DNA::from(bits << 2 | bits >> 2)
     }
+
+    /// Strict parsing of a single IUPAC nucleotide code. Unlike `From<char>`,
+    /// ambiguity codes are kept, `-` and `.` are read as gaps and anything
+    /// else is rejected.
+    pub fn from_iupac(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            'A' => Some(DNA::A),
+            'C' => Some(DNA::C),
+            'G' => Some(DNA::G),
+            'T' => Some(DNA::T),
+            'Y' => Some(DNA::Y),
+            'W' => Some(DNA::W),
+            'M' => Some(DNA::M),
+            'K' => Some(DNA::K),
+            'S' => Some(DNA::S),
+            'R' => Some(DNA::R),
+            'H' => Some(DNA::H),
+            'B' => Some(DNA::B),
+            'D' => Some(DNA::D),
+            'V' => Some(DNA::V),
+            'N' => Some(DNA::N),
+            '-' | '.' => Some(DNA::Gap),
+            _ => None,
+        }
+    }
+}
+
+/// The reverse complement of a DNA strand. Ambiguity codes are complemented too.
+pub fn reverse_complement(seq: &[DNA]) -> Vec<DNA> {
+    seq.iter()
+        .rev()
+        .map(|base| base.complement())
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::convert::TryFrom;
     use seq::codon::Codon;
     use seq::translation::ncbi_translation_tables::STANDARD;
     use seq::translation::TranslatedCodon;
@@ -163,6 +219,16 @@ mod tests {
         assert_eq!(DNA::from('4'), DNA::N);
     }
 
+    #[test]
+    fn dna_iupac_round_trip() {
+        for c in "ACGTYWMKSRHBDVN-".chars() {
+            assert_eq!(DNA::from_iupac(c).map(char::from), Some(c));
+        }
+        assert_eq!(DNA::from_iupac('r'), Some(DNA::R));
+        assert_eq!(DNA::from_iupac('.'), Some(DNA::Gap));
+        assert_eq!(DNA::from_iupac('Z'), None);
+    }
+
     #[test]
     fn dna_bit_or() {
         assert_eq!(DNA::A | DNA::A, DNA::A);
@@ -212,6 +278,13 @@ mod tests {
         assert_eq!(DNA::K.complement(), DNA::M);
     }
 
+    #[test]
+    fn test_reverse_complement() {
+        let input: Vec<DNA> = "AAAACCCGGT".chars().map(DNA::from).collect();
+        let output: String = reverse_complement(&input).into_iter().map(char::from).collect();
+        assert_eq!(output, "ACCGGGTTTT");
+    }
+
     //noinspection ALL
     #[test]
     fn dna_from_string() {
@@ -0,0 +1,84 @@
+use std::iter::FromIterator;
+use seq::nuc::DNA;
+
+/// A DNA sequence stored two bases to a byte using `DNA`'s four-bit codes,
+/// so ambiguity codes and gaps survive packing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PackedDna {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl PackedDna {
+    pub fn new() -> Self {
+        PackedDna::default()
+    }
+
+    pub fn with_capacity(bases: usize) -> Self {
+        PackedDna {
+            bytes: Vec::with_capacity(bases.div_ceil(2)),
+            len: 0,
+        }
+    }
+
+    pub fn push(&mut self, base: DNA) {
+        let code = base as u8;
+        if self.len.is_multiple_of(2) {
+            self.bytes.push(code);
+        } else {
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= code << 4;
+        }
+        self.len += 1;
+    }
+
+    pub fn get(&self, idx: usize) -> Option<DNA> {
+        if idx >= self.len {
+            return None;
+        }
+        Some(DNA::from(self.bytes[idx / 2] >> (4 * (idx % 2))))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = DNA> + '_ {
+        (0..self.len).map(move |idx| self.get(idx).expect("index is in bounds"))
+    }
+
+    /// The packed bytes; the high nibble of the last byte is unused for odd lengths.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl FromIterator<DNA> for PackedDna {
+    fn from_iter<I: IntoIterator<Item = DNA>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut packed = PackedDna::with_capacity(iter.size_hint().0);
+        for base in iter {
+            packed.push(base);
+        }
+        packed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_two_bases_per_byte() {
+        let bases = vec![DNA::A, DNA::C, DNA::N, DNA::Gap, DNA::T];
+        let packed: PackedDna = bases.iter().cloned().collect();
+        assert_eq!(packed.len(), 5);
+        assert_eq!(packed.as_bytes().len(), 3);
+        assert_eq!(packed.iter().collect::<Vec<_>>(), bases);
+        assert_eq!(packed.get(5), None);
+    }
+}
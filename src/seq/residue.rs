@@ -0,0 +1,74 @@
+use seq::nuc::DNA;
+use seq::translation::TranslatedCodon;
+
+/// A single symbol in a (possibly gapped) biological sequence.
+///
+/// This lets containers like `msa::Alignment` work over both nucleotides and
+/// amino acids while agreeing on a single gap symbol per alphabet.
+pub trait Residue: Copy + PartialEq {
+    /// The gap symbol for this alphabet.
+    fn gap() -> Self;
+
+    /// Strictly parse a character, returning `None` if it is not part of the alphabet.
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(self) -> char;
+
+    fn is_gap(self) -> bool {
+        self == Self::gap()
+    }
+}
+
+impl Residue for DNA {
+    fn gap() -> Self {
+        DNA::Gap
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        DNA::from_iupac(c)
+    }
+
+    fn to_char(self) -> char {
+        char::from(self)
+    }
+}
+
+impl Residue for TranslatedCodon {
+    fn gap() -> Self {
+        TranslatedCodon::Gap
+    }
+
+    /// The twenty standard amino acids, `X`, stops and gaps, in either case.
+    /// Codes with no residue of their own here (`B`, `J`, `O`, `U` and `Z`)
+    /// are rejected rather than read as `X`, which would lose them.
+    fn from_char(c: char) -> Option<Self> {
+        match c.to_ascii_uppercase() {
+            '.' => Some(TranslatedCodon::Gap),
+            '*' | '-' => Some(TranslatedCodon::from(c)),
+            'B' | 'J' | 'O' | 'U' | 'Z' => None,
+            c if c.is_ascii_uppercase() => Some(TranslatedCodon::from(c)),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        char::from(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protein_residues() {
+        assert_eq!(TranslatedCodon::from_char('m'), Some(TranslatedCodon::M));
+        assert_eq!(TranslatedCodon::from_char('.'), Some(TranslatedCodon::Gap));
+        assert_eq!(TranslatedCodon::from_char('x'), Some(TranslatedCodon::X));
+        assert_eq!(TranslatedCodon::from_char('B'), None);
+        assert_eq!(TranslatedCodon::from_char('j'), None);
+        assert_eq!(TranslatedCodon::from_char('1'), None);
+        assert!(TranslatedCodon::Gap.is_gap());
+        assert_eq!(TranslatedCodon::Stop.to_char(), '*');
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use seq::nuc::DNA;
+
+/// An RNA sequence. It shares `DNA`'s encoding, with `DNA::T` standing for uracil.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rna(Vec<DNA>);
+
+impl Rna {
+    /// Transcribe a DNA coding strand.
+    pub fn transcribe(dna: &[DNA]) -> Self {
+        Rna(dna.to_vec())
+    }
+
+    /// The equivalent DNA coding strand.
+    pub fn bases(&self) -> &[DNA] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Strict parsing of a single IUPAC code, reading `U` for uracil and rejecting `T`.
+    pub fn base_from_char(c: char) -> Option<DNA> {
+        match c.to_ascii_uppercase() {
+            'U' => Some(DNA::T),
+            'T' => None,
+            c => DNA::from_iupac(c),
+        }
+    }
+
+    /// Parse a single IUPAC code, reading either `U` or `T` for uracil, so a DNA coding
+    /// strand reads as its own transcript.
+    pub fn coding_base_from_char(c: char) -> Option<DNA> {
+        match c.to_ascii_uppercase() {
+            'U' => Some(DNA::T),
+            c => DNA::from_iupac(c),
+        }
+    }
+}
+
+impl From<Vec<DNA>> for Rna {
+    fn from(bases: Vec<DNA>) -> Self {
+        Rna(bases)
+    }
+}
+
+impl fmt::Display for Rna {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &base in &self.0 {
+            let c = match base {
+                DNA::T => 'U',
+                other => char::from(other),
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uracil_round_trip() {
+        let rna: Rna = "GAUC".chars().map(|c| Rna::base_from_char(c).unwrap()).collect::<Vec<_>>().into();
+        assert_eq!(rna.bases(), &[DNA::G, DNA::A, DNA::T, DNA::C]);
+        assert_eq!(rna.to_string(), "GAUC");
+        assert_eq!(Rna::base_from_char('T'), None);
+        assert_eq!(Rna::coding_base_from_char('t'), Some(DNA::T));
+        assert_eq!(Rna::coding_base_from_char('u'), Some(DNA::T));
+    }
+}
@@ -1,4 +1,8 @@
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+use std::convert::TryFrom;
+use seq::codon::Codon;
+use seq::nuc::DNA;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
 pub enum TranslatedCodon {
     A, // Ala - Alanine
     C, // Cys - Cysteine
@@ -20,8 +24,10 @@ pub enum TranslatedCodon {
     V, // Val - Valine
     W, // Trp - Tryptophan
     Y, // Tyr - Tyrosine
+    #[default]
     X, // Any amino acid
     Stop, // Terminator
+    Gap, // Alignment gap
 }
 
 impl From<char> for TranslatedCodon {
@@ -48,6 +54,7 @@ impl From<char> for TranslatedCodon {
             'W' => TranslatedCodon::W,
             'Y' => TranslatedCodon::Y,
             '*' => TranslatedCodon::Stop,
+            '-' => TranslatedCodon::Gap,
             _ => TranslatedCodon::X,
         }
     }
@@ -77,28 +84,32 @@ impl From<TranslatedCodon> for char {
             TranslatedCodon::W => 'W',
             TranslatedCodon::Y => 'Y',
             TranslatedCodon::Stop => '*',
+            TranslatedCodon::Gap => '-',
             _ => 'X',
         }
     }
 }
 
-impl Default for TranslatedCodon {
-    fn default() -> Self {
-        TranslatedCodon::X
-    }
-}
-
 pub struct TranslationTable([TranslatedCodon; 64]);
 
 impl TranslationTable {
     pub fn get(&self, idx: usize) -> TranslatedCodon {
         match self.0.get(idx) {
             None => TranslatedCodon::default(),
-            Some(codon) => codon.clone(),
+            Some(codon) => *codon,
         }
     }
 }
 
+/// Translate a coding sequence codon by codon, including any stop codons.
+/// A trailing partial codon is ignored.
+pub fn translate(seq: &[DNA], table: &TranslationTable) -> Vec<TranslatedCodon> {
+    seq.chunks_exact(3)
+        .filter_map(|chunk| Codon::try_from(chunk).ok())
+        .map(|codon| codon.translate(table))
+        .collect()
+}
+
 pub mod ncbi_translation_tables {
     use seq::translation::{TranslationTable,TranslatedCodon::*};
     pub static STANDARD: TranslationTable = TranslationTable([F,F,L,L,S,S,S,S,Y,Y,Stop,Stop,C,C,Stop,W,L,L,L,L,P,P,P,P,H,H,Q,Q,R,R,R,R,I,I,I,M,T,T,T,T,N,N,K,K,S,S,R,R,V,V,V,V,A,A,A,A,D,D,E,E,G,G,G,G]);
@@ -110,6 +121,17 @@ mod tests {
     use super::*;
     use super::ncbi_translation_tables::*;
 
+    //noinspection ALL
+    #[test]
+    fn test_translate() {
+        let seq: Vec<DNA> = "AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA"
+            .chars()
+            .map(|c| if c == 'U' { DNA::T } else { DNA::from(c) })
+            .collect();
+        let protein: String = translate(&seq, &STANDARD).into_iter().map(char::from).collect();
+        assert_eq!(protein, "MAMAPRTEINSTRING*");
+    }
+
     #[test]
     fn test_get() {
         assert_eq!(STANDARD.get(0), TranslatedCodon::F);
@@ -0,0 +1,124 @@
+use std::convert::TryFrom;
+use simulate::{Alphabet, Composition, Rng};
+
+/// A fixed-order Markov chain over an alphabet: the next letter depends on the
+/// `order` letters before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkovModel {
+    alphabet: Alphabet,
+    order: usize,
+    /// Letter frequencies, used to start each sequence.
+    initial: Composition,
+    /// One row of next-letter weights per context, indexed by the context's
+    /// letters read as a base-`alphabet size` number.
+    transitions: Vec<Vec<f64>>,
+}
+
+impl MarkovModel {
+    /// Most transition weights a model may hold, about 32 MB.
+    pub const MAX_TABLE_SIZE: usize = 1 << 22;
+
+    /// Number of transition weights a model of this order needs, or `None`
+    /// when it would exceed `MAX_TABLE_SIZE`.
+    pub fn table_size(alphabet: Alphabet, order: usize) -> Option<usize> {
+        let size = alphabet.letters().len();
+        u32::try_from(order).ok()
+            .and_then(|order| size.checked_pow(order))
+            .and_then(|contexts| contexts.checked_mul(size))
+            .filter(|&table| table <= Self::MAX_TABLE_SIZE)
+    }
+
+    /// Count transitions in `seqs`, adding one to every count so unseen
+    /// contexts and letters stay possible. Characters outside the alphabet
+    /// break the chain.
+    ///
+    /// The order must leave the table within `MAX_TABLE_SIZE`; see `table_size`.
+    pub fn train<'a, I>(alphabet: Alphabet, order: usize, seqs: I) -> Self
+        where I: IntoIterator<Item = &'a [u8]>
+    {
+        let size = alphabet.letters().len();
+        let mut initial = vec![1.0; size];
+        let mut transitions = vec![vec![1.0; size]; size.pow(order as u32)];
+
+        for seq in seqs {
+            let mut context = 0;
+            let mut run = 0;
+            for &byte in seq {
+                match alphabet.index(byte) {
+                    Some(idx) => {
+                        initial[idx] += 1.0;
+                        if run >= order {
+                            transitions[context][idx] += 1.0;
+                        }
+                        context = push_context(context, idx, size, order);
+                        run += 1;
+                    }
+                    None => run = 0,
+                }
+            }
+        }
+
+        MarkovModel {
+            alphabet,
+            order,
+            initial: Composition::from_weights(alphabet, initial).expect("counts are positive"),
+            transitions,
+        }
+    }
+
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    pub fn generate(&self, length: usize, rng: &mut Rng) -> Vec<u8> {
+        let size = self.alphabet.letters().len();
+        let mut seq = Vec::with_capacity(length);
+        let mut context = 0;
+        for position in 0..length {
+            let idx = if position < self.order {
+                rng.weighted(self.initial.weights())
+            } else {
+                rng.weighted(&self.transitions[context])
+            };
+            seq.push(self.alphabet.letters()[idx]);
+            context = push_context(context, idx, size, self.order);
+        }
+        seq
+    }
+}
+
+/// Slide a letter into a context, dropping the oldest one.
+fn push_context(context: usize, idx: usize, size: usize, order: usize) -> usize {
+    if order == 0 {
+        0
+    } else {
+        (context * size + idx) % size.pow(order as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn learns_transitions() {
+        let training = b"ACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACACAC".to_vec();
+        let model = MarkovModel::train(Alphabet::Dna, 1, vec![&training[..]]);
+        let mut rng = Rng::seed_from_u64(3);
+        let seq = model.generate(1000, &mut rng);
+        let alternations = seq.windows(2).filter(|w| w == b"AC" || w == b"CA").count();
+        assert!(alternations > 800, "only {} alternations", alternations);
+        assert_eq!(model.order(), 1);
+    }
+
+    #[test]
+    fn bounded_tables() {
+        assert_eq!(MarkovModel::table_size(Alphabet::Dna, 2), Some(64));
+        assert_eq!(MarkovModel::table_size(Alphabet::Dna, 40), None);
+        assert_eq!(MarkovModel::table_size(Alphabet::Protein, 9), None);
+    }
+}
@@ -0,0 +1,294 @@
+//! Reproducible random sequences, planted motifs and error-prone reads, for
+//! benchmarking and testing solvers on more than the Rosalind samples.
+
+use std::str::FromStr;
+use error::{Error, Result};
+
+mod markov;
+mod reads;
+mod rng;
+
+pub use self::markov::MarkovModel;
+pub use self::reads::{ErrorModel, SimulatedRead, MAX_QUALITY};
+pub use self::rng::Rng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alphabet {
+    Dna,
+    Rna,
+    Protein,
+}
+
+pub const ALPHABET_NAMES: &[&str] = &["dna", "rna", "protein"];
+
+impl Alphabet {
+    pub fn letters(self) -> &'static [u8] {
+        match self {
+            Alphabet::Dna => b"ACGT",
+            Alphabet::Rna => b"ACGU",
+            Alphabet::Protein => b"ACDEFGHIKLMNPQRSTVWY",
+        }
+    }
+
+    /// Position of `letter` in `letters()`, ignoring case.
+    pub fn index(self, letter: u8) -> Option<usize> {
+        let letter = letter.to_ascii_uppercase();
+        self.letters().iter().position(|&l| l == letter)
+    }
+}
+
+impl FromStr for Alphabet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "dna" => Ok(Alphabet::Dna),
+            "rna" => Ok(Alphabet::Rna),
+            "protein" => Ok(Alphabet::Protein),
+            _ => Err(Error::usage(format!("Unknown alphabet '{}', expected one of {}", s, ALPHABET_NAMES.join(", ")))),
+        }
+    }
+}
+
+/// Relative frequencies of each letter of an alphabet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Composition {
+    alphabet: Alphabet,
+    weights: Vec<f64>,
+}
+
+impl Composition {
+    pub fn uniform(alphabet: Alphabet) -> Self {
+        Composition { alphabet, weights: vec![1.0; alphabet.letters().len()] }
+    }
+
+    /// Nucleotides with a G+C fraction of `gc`, split evenly between G and C
+    /// and between A and T (or U).
+    pub fn gc_content(alphabet: Alphabet, gc: f64) -> Result<Self> {
+        if alphabet == Alphabet::Protein {
+            return Err(Error::usage("GC content only applies to nucleotides"));
+        }
+        if !(0.0..=1.0).contains(&gc) {
+            return Err(Error::usage(format!("GC content must be between 0 and 1, not {}", gc)));
+        }
+        let (at, gc) = ((1.0 - gc) / 2.0, gc / 2.0);
+        Composition::from_weights(alphabet, vec![at, gc, gc, at])
+    }
+
+    /// Parse letter weights such as `A=0.3,C=0.2,G=0.2,T=0.3`. Letters left
+    /// out never appear, and the weights needn't add up to one.
+    pub fn parse(alphabet: Alphabet, spec: &str) -> Result<Self> {
+        let mut weights = vec![0.0; alphabet.letters().len()];
+        for part in spec.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let invalid = || Error::usage(format!("Invalid composition entry '{}', expected LETTER=WEIGHT", part));
+            let (letter, weight) = part.split_once('=').ok_or_else(invalid)?;
+            let idx = match letter.trim().as_bytes() {
+                &[letter] => alphabet.index(letter),
+                _ => None,
+            }.ok_or_else(|| Error::usage(format!("'{}' is not a letter of the alphabet", letter.trim())))?;
+            weights[idx] = weight.trim().parse().map_err(|_| invalid())?;
+        }
+        Composition::from_weights(alphabet, weights)
+    }
+
+    pub fn from_weights(alphabet: Alphabet, weights: Vec<f64>) -> Result<Self> {
+        if weights.len() != alphabet.letters().len() {
+            return Err(Error::usage("Need one weight per letter of the alphabet"));
+        }
+        if weights.iter().any(|&w| !w.is_finite() || w < 0.0) || weights.iter().sum::<f64>() <= 0.0 {
+            return Err(Error::usage("Composition weights must be non-negative and not all zero"));
+        }
+        Ok(Composition { alphabet, weights })
+    }
+
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> u8 {
+        self.alphabet.letters()[rng.weighted(&self.weights)]
+    }
+}
+
+/// The model random sequence is drawn from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Background {
+    /// Each letter independent of the others.
+    Composition(Composition),
+    Markov(MarkovModel),
+}
+
+impl Background {
+    pub fn alphabet(&self) -> Alphabet {
+        match self {
+            Background::Composition(composition) => composition.alphabet(),
+            Background::Markov(model) => model.alphabet(),
+        }
+    }
+
+    pub fn generate(&self, length: usize, rng: &mut Rng) -> Vec<u8> {
+        match self {
+            Background::Composition(composition) => (0..length).map(|_| composition.sample(rng)).collect(),
+            Background::Markov(model) => model.generate(length, rng),
+        }
+    }
+}
+
+/// A motif to hide in each sequence, with each copy mutated independently.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Motif {
+    pub seq: Vec<u8>,
+    /// Chance that each position of a planted copy is changed to another letter.
+    pub mutation_rate: f64,
+}
+
+impl Motif {
+    /// Overwrite a random stretch of `seq` with a mutated copy of the motif,
+    /// returning where it starts, or `None` if the motif doesn't fit.
+    pub fn plant(&self, alphabet: Alphabet, seq: &mut [u8], rng: &mut Rng) -> Option<usize> {
+        if self.seq.len() > seq.len() {
+            return None;
+        }
+        let start = rng.below(seq.len() - self.seq.len() + 1);
+        for (target, &letter) in seq[start..].iter_mut().zip(&self.seq) {
+            *target = if rng.chance(self.mutation_rate) {
+                substitute(alphabet, letter, rng)
+            } else {
+                letter
+            };
+        }
+        Some(start)
+    }
+}
+
+/// A random letter other than `letter`.
+fn substitute(alphabet: Alphabet, letter: u8, rng: &mut Rng) -> u8 {
+    let letters = alphabet.letters();
+    match alphabet.index(letter) {
+        Some(idx) => letters[(idx + 1 + rng.below(letters.len() - 1)) % letters.len()],
+        None => letters[rng.below(letters.len())],
+    }
+}
+
+/// A generated sequence, with where its motif copy was planted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedSeq {
+    pub id: String,
+    pub seq: Vec<u8>,
+    pub motif_start: Option<usize>,
+}
+
+/// Generate `count` sequences of `length` letters named `seq1`, `seq2`, ...
+pub fn simulate_sequences(background: &Background, motif: Option<&Motif>, count: usize, length: usize, rng: &mut Rng)
+    -> Vec<SimulatedSeq>
+{
+    (1..=count).map(|n| {
+        let mut seq = background.generate(length, rng);
+        let motif_start = motif.and_then(|motif| motif.plant(background.alphabet(), &mut seq, rng));
+        SimulatedSeq { id: format!("seq{}", n), seq, motif_start }
+    }).collect()
+}
+
+/// Reads to draw from the simulated sequences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadSimulation {
+    pub count: usize,
+    pub length: usize,
+    pub errors: ErrorModel,
+}
+
+/// Everything needed to reproduce a simulated dataset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    pub seed: u64,
+    pub count: usize,
+    pub length: usize,
+    pub background: Background,
+    pub motif: Option<Motif>,
+    pub reads: Option<ReadSimulation>,
+}
+
+impl Simulation {
+    /// The generated sequences, and reads drawn from them if any were asked for.
+    pub fn run(&self) -> (Vec<SimulatedSeq>, Vec<SimulatedRead>) {
+        let mut rng = Rng::seed_from_u64(self.seed);
+        let seqs = simulate_sequences(&self.background, self.motif.as_ref(), self.count, self.length, &mut rng);
+        let reads = match self.reads {
+            Some(ref reads) => {
+                let sources: Vec<&[u8]> = seqs.iter().map(|s| &s.seq[..]).collect();
+                (0..reads.count)
+                    .filter_map(|_| SimulatedRead::simulate(&sources, reads.length, &reads.errors, self.background.alphabet(), &mut rng))
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        (seqs, reads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compositions() {
+        let gc = Composition::gc_content(Alphabet::Dna, 0.6).unwrap();
+        assert_eq!(gc.weights(), &[0.2, 0.3, 0.3, 0.2]);
+        assert!(Composition::gc_content(Alphabet::Protein, 0.5).is_err());
+        assert!(Composition::gc_content(Alphabet::Dna, 1.5).is_err());
+
+        let parsed = Composition::parse(Alphabet::Rna, "a=1, U=3").unwrap();
+        assert_eq!(parsed.weights(), &[1.0, 0.0, 0.0, 3.0]);
+        assert!(Composition::parse(Alphabet::Dna, "U=1").is_err());
+        assert!(Composition::parse(Alphabet::Dna, "A=-1").is_err());
+        assert!(Composition::parse(Alphabet::Dna, "").is_err());
+    }
+
+    #[test]
+    fn generated_sequences_follow_the_composition() {
+        let mut rng = Rng::seed_from_u64(7);
+        let background = Background::Composition(Composition::gc_content(Alphabet::Dna, 0.7).unwrap());
+        let seq = background.generate(20000, &mut rng);
+        let gc = seq.iter().filter(|&&b| b == b'G' || b == b'C').count() as f64 / seq.len() as f64;
+        assert!((gc - 0.7).abs() < 0.02, "gc = {}", gc);
+    }
+
+    #[test]
+    fn planted_motifs() {
+        let mut rng = Rng::seed_from_u64(1);
+        let background = Background::Composition(Composition::uniform(Alphabet::Dna));
+        let motif = Motif { seq: b"GATTACA".to_vec(), mutation_rate: 0.0 };
+        let seqs = simulate_sequences(&background, Some(&motif), 3, 50, &mut rng);
+        assert_eq!(seqs[2].id, "seq3");
+        for seq in &seqs {
+            let start = seq.motif_start.unwrap();
+            assert_eq!(&seq.seq[start..start + 7], b"GATTACA");
+        }
+
+        let mutated = Motif { mutation_rate: 1.0, ..motif };
+        let mut seq = vec![b'A'; 7];
+        assert_eq!(mutated.plant(Alphabet::Dna, &mut seq, &mut rng), Some(0));
+        assert!(seq.iter().zip(b"GATTACA").all(|(a, b)| a != b));
+        assert_eq!(mutated.plant(Alphabet::Dna, &mut [b'A'; 3], &mut rng), None);
+    }
+
+    #[test]
+    fn simulations_are_reproducible() {
+        let simulation = Simulation {
+            seed: 99,
+            count: 2,
+            length: 100,
+            background: Background::Composition(Composition::uniform(Alphabet::Protein)),
+            motif: None,
+            reads: Some(ReadSimulation { count: 5, length: 30, errors: ErrorModel::default() }),
+        };
+        let (seqs, reads) = simulation.run();
+        assert_eq!((seqs.len(), reads.len()), (2, 5));
+        assert_eq!(simulation.run(), (seqs, reads));
+        assert_ne!(Simulation { seed: 100, ..simulation.clone() }.run().0, simulation.run().0);
+    }
+}
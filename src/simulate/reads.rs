@@ -0,0 +1,130 @@
+use simulate::{substitute, Alphabet, Rng};
+
+/// Highest Phred score given to a simulated base.
+pub const MAX_QUALITY: u8 = 40;
+const MIN_QUALITY: u8 = 2;
+
+/// Per-base error rates for simulated reads.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ErrorModel {
+    pub substitution: f64,
+    pub insertion: f64,
+    pub deletion: f64,
+}
+
+impl ErrorModel {
+    pub fn total(&self) -> f64 {
+        self.substitution + self.insertion + self.deletion
+    }
+
+    /// The Phred score matching the overall error rate.
+    fn expected_quality(&self) -> u8 {
+        let total = self.total().max(1e-4);
+        (-10.0 * total.log10()).round().clamp(f64::from(MIN_QUALITY), f64::from(MAX_QUALITY)) as u8
+    }
+}
+
+/// A read drawn from a source sequence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedRead {
+    /// Index of the source sequence the read was taken from.
+    pub source: usize,
+    /// Zero-based offset of the read in its source.
+    pub start: usize,
+    pub seq: Vec<u8>,
+    /// Phred scores, one per base of `seq`.
+    pub qual: Vec<u8>,
+    /// How many substitutions, insertions and deletions were made.
+    pub errors: usize,
+}
+
+impl SimulatedRead {
+    /// Draw a read of about `length` bases from a random source and position,
+    /// applying `model`'s errors. Sources shorter than `length` give shorter
+    /// reads. `None` if there is nothing to read from.
+    pub fn simulate(sources: &[&[u8]], length: usize, model: &ErrorModel, alphabet: Alphabet, rng: &mut Rng)
+        -> Option<SimulatedRead>
+    {
+        if sources.iter().all(|s| s.is_empty()) {
+            return None;
+        }
+        let source = loop {
+            let idx = rng.below(sources.len());
+            if !sources[idx].is_empty() {
+                break idx;
+            }
+        };
+        let template = sources[source];
+        let length = length.min(template.len());
+        let start = rng.below(template.len() - length + 1);
+
+        let good = model.expected_quality();
+        let mut read = SimulatedRead { source, start, seq: Vec::new(), qual: Vec::new(), errors: 0 };
+        for &letter in &template[start..start + length] {
+            if rng.chance(model.insertion) {
+                read.push_error(alphabet.letters()[rng.below(alphabet.letters().len())], good, rng);
+            }
+            if rng.chance(model.deletion) {
+                read.errors += 1;
+            } else if rng.chance(model.substitution) {
+                read.push_error(substitute(alphabet, letter, rng), good, rng);
+            } else {
+                let quality = jitter(good, rng);
+                read.seq.push(letter);
+                read.qual.push(quality);
+            }
+        }
+        Some(read)
+    }
+
+    /// Erroneous bases get a markedly lower score than correct ones.
+    fn push_error(&mut self, letter: u8, good: u8, rng: &mut Rng) {
+        self.seq.push(letter);
+        self.qual.push(jitter(good / 3, rng));
+        self.errors += 1;
+    }
+
+    /// Quality scores as FASTQ characters, with the usual offset of 33.
+    pub fn qual_string(&self) -> String {
+        self.qual.iter().map(|&q| char::from(q + b'!')).collect()
+    }
+}
+
+/// Vary a score by up to two either way.
+fn jitter(quality: u8, rng: &mut Rng) -> u8 {
+    let shifted = i32::from(quality) + rng.below(5) as i32 - 2;
+    shifted.clamp(i32::from(MIN_QUALITY), i32::from(MAX_QUALITY)) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_free_reads_match_the_source() {
+        let mut rng = Rng::seed_from_u64(11);
+        let source = b"ACGTTGCAACGGTACCATGA";
+        let read = SimulatedRead::simulate(&[&source[..]], 8, &ErrorModel::default(), Alphabet::Dna, &mut rng).unwrap();
+        assert_eq!(read.seq, &source[read.start..read.start + 8]);
+        assert_eq!(read.errors, 0);
+        assert!(read.qual.iter().all(|&q| q >= MAX_QUALITY - 2));
+        assert_eq!(read.qual_string().len(), 8);
+        assert_eq!(SimulatedRead::simulate(&[&b""[..]], 8, &ErrorModel::default(), Alphabet::Dna, &mut rng), None);
+    }
+
+    #[test]
+    fn errors_change_reads() {
+        let mut rng = Rng::seed_from_u64(5);
+        let source = vec![b'A'; 1000];
+        let deletions = ErrorModel { deletion: 0.5, ..ErrorModel::default() };
+        let read = SimulatedRead::simulate(&[&source[..]], 1000, &deletions, Alphabet::Dna, &mut rng).unwrap();
+        assert!(read.seq.len() < 600 && read.seq.len() + read.errors == 1000);
+
+        let substitutions = ErrorModel { substitution: 0.1, ..ErrorModel::default() };
+        let read = SimulatedRead::simulate(&[&source[..]], 1000, &substitutions, Alphabet::Dna, &mut rng).unwrap();
+        let changed = read.seq.iter().filter(|&&b| b != b'A').count();
+        assert_eq!(changed, read.errors);
+        assert!(changed > 50 && changed < 150);
+        assert_eq!(read.qual.len(), read.seq.len());
+    }
+}
@@ -0,0 +1,232 @@
+//! Summary statistics over a set of sequences, as used for assembly and read QC.
+
+use std::io;
+use std::io::BufRead;
+use error::Result;
+use io::{FastaReader, FastqReader, Record};
+use seq::nuc::DNA;
+
+/// `Nx`: the length `l` such that sequences at least `l` long cover `x`
+/// percent of the total length. `None` for an empty set.
+pub fn n_x(lengths: &[usize], x: u32) -> Option<usize> {
+    x_stat(lengths, x).map(|(length, _)| length)
+}
+
+/// `Lx`: how few sequences it takes to cover `x` percent of the total length.
+pub fn l_x(lengths: &[usize], x: u32) -> Option<usize> {
+    x_stat(lengths, x).map(|(_, count)| count)
+}
+
+fn x_stat(lengths: &[usize], x: u32) -> Option<(usize, usize)> {
+    let mut sorted = lengths.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    let total: u64 = sorted.iter().map(|&l| l as u64).sum();
+    let mut covered = 0;
+    for (idx, &length) in sorted.iter().enumerate() {
+        covered += length as u64;
+        if covered * 100 >= total * u64::from(x) {
+            return Some((length, idx + 1));
+        }
+    }
+    None
+}
+
+/// Phred quality totals for FASTQ input.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QualityStats {
+    pub bases: u64,
+    pub sum: u64,
+    /// Bases with a score of at least 20 and at least 30.
+    pub q20: u64,
+    pub q30: u64,
+}
+
+impl QualityStats {
+    pub fn add<I: IntoIterator<Item = u8>>(&mut self, scores: I) {
+        for score in scores {
+            self.bases += 1;
+            self.sum += u64::from(score);
+            self.q20 += u64::from(score >= 20);
+            self.q30 += u64::from(score >= 30);
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sum as f64 / self.bases as f64
+    }
+
+    pub fn q20_percent(&self) -> f64 {
+        100.0 * self.q20 as f64 / self.bases as f64
+    }
+
+    pub fn q30_percent(&self) -> f64 {
+        100.0 * self.q30 as f64 / self.bases as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeqFormat {
+    Fasta,
+    Fastq,
+}
+
+/// Counts gathered one record at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeqStats {
+    pub format: SeqFormat,
+    pub lengths: Vec<usize>,
+    pub gc: u64,
+    /// `A`, `C`, `G` and `T` bases, the denominator of the GC content.
+    pub unambiguous: u64,
+    /// Bases with an IUPAC ambiguity code, such as `N` or `R`.
+    pub ambiguous: u64,
+    /// Only present for FASTQ.
+    pub quality: Option<QualityStats>,
+}
+
+impl SeqStats {
+    pub fn new(format: SeqFormat) -> Self {
+        SeqStats {
+            format,
+            lengths: Vec::new(),
+            gc: 0,
+            unambiguous: 0,
+            ambiguous: 0,
+            quality: match format {
+                SeqFormat::Fasta => None,
+                SeqFormat::Fastq => Some(QualityStats::default()),
+            },
+        }
+    }
+
+    pub fn add(&mut self, record: &Record) {
+        self.lengths.push(record.seq().len());
+        self.gc += record.gc_count() as u64;
+        self.unambiguous += record.seq().chars().filter(|&c| is_unambiguous(c)).count() as u64;
+        self.ambiguous += record.seq().chars().filter(|&c| is_ambiguous(c)).count() as u64;
+    }
+
+    pub fn count(&self) -> usize {
+        self.lengths.len()
+    }
+
+    pub fn total_length(&self) -> u64 {
+        self.lengths.iter().map(|&l| l as u64).sum()
+    }
+
+    pub fn min_length(&self) -> Option<usize> {
+        self.lengths.iter().cloned().min()
+    }
+
+    pub fn max_length(&self) -> Option<usize> {
+        self.lengths.iter().cloned().max()
+    }
+
+    /// `None` when there are no sequences.
+    pub fn mean_length(&self) -> Option<f64> {
+        match self.count() {
+            0 => None,
+            count => Some(self.total_length() as f64 / count as f64),
+        }
+    }
+
+    pub fn n_x(&self, x: u32) -> Option<usize> {
+        n_x(&self.lengths, x)
+    }
+
+    pub fn l_x(&self, x: u32) -> Option<usize> {
+        l_x(&self.lengths, x)
+    }
+
+    /// Share of the unambiguous bases that are `C` or `G`, leaving out `N`s and gaps.
+    /// `None` when there are no unambiguous bases.
+    pub fn gc_percent(&self) -> Option<f64> {
+        match self.unambiguous {
+            0 => None,
+            total => Some(100.0 * self.gc as f64 / total as f64),
+        }
+    }
+}
+
+fn is_unambiguous(c: char) -> bool {
+    matches!(DNA::from_iupac(c), Some(DNA::A) | Some(DNA::C) | Some(DNA::G) | Some(DNA::T))
+}
+
+fn is_ambiguous(c: char) -> bool {
+    match DNA::from_iupac(c) {
+        Some(DNA::A) | Some(DNA::C) | Some(DNA::G) | Some(DNA::T) | Some(DNA::Gap) | None => false,
+        Some(_) => true,
+    }
+}
+
+/// Gather statistics from FASTA or FASTQ, telling them apart by the first
+/// non-blank character.
+pub fn read_stats<R: io::Read>(reader: R) -> Result<SeqStats> {
+    let mut reader = io::BufReader::new(reader);
+    let format = loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break SeqFormat::Fasta;
+        }
+        match buffer.iter().position(|b| !b.is_ascii_whitespace()) {
+            Some(idx) if buffer[idx] == b'@' => break SeqFormat::Fastq,
+            Some(_) => break SeqFormat::Fasta,
+            None => {
+                let len = buffer.len();
+                reader.consume(len);
+            }
+        }
+    };
+
+    let mut stats = SeqStats::new(format);
+    match format {
+        SeqFormat::Fasta => {
+            for record in FastaReader::new(reader) {
+                stats.add(&record?);
+            }
+        }
+        SeqFormat::Fastq => {
+            for fastq in FastqReader::new(reader) {
+                let fastq = fastq?;
+                stats.add(fastq.record());
+                if let Some(ref mut quality) = stats.quality {
+                    quality.add(fastq.phred_scores());
+                }
+            }
+        }
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assembly_statistics() {
+        // The ASMQ sample dataset.
+        let lengths = [9, 8, 7, 6, 5, 4];
+        assert_eq!(n_x(&lengths, 50), Some(7));
+        assert_eq!(n_x(&lengths, 75), Some(6));
+        assert_eq!(l_x(&lengths, 50), Some(3));
+        assert_eq!(n_x(&[], 50), None);
+    }
+
+    #[test]
+    fn fasta_and_fastq() {
+        let fasta = read_stats(&b">a\nACGTNN\n>b\nGGCR\n"[..]).unwrap();
+        assert_eq!(fasta.format, SeqFormat::Fasta);
+        assert_eq!((fasta.count(), fasta.total_length()), (2, 10));
+        assert_eq!((fasta.min_length(), fasta.max_length()), (Some(4), Some(6)));
+        assert_eq!(fasta.gc_percent(), Some(500.0 / 7.0));
+        assert_eq!(SeqStats::new(SeqFormat::Fasta).mean_length(), None);
+        assert_eq!(fasta.ambiguous, 3);
+        assert_eq!(fasta.quality, None);
+
+        let fastq = read_stats(&b"\n@r1\nACGT\n+\nII55\n"[..]).unwrap();
+        assert_eq!(fastq.format, SeqFormat::Fastq);
+        let quality = fastq.quality.unwrap();
+        assert_eq!(quality.mean(), 30.0);
+        assert_eq!((quality.q20, quality.q30), (4, 2));
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod newick;
+
+pub use self::newick::NewickError;
+
+/// Index of a node within its `Tree`.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Node {
+    pub label: Option<String>,
+    /// Length of the branch leading to this node from its parent.
+    pub branch_length: Option<f64>,
+    /// Support for the branch leading to this node (bootstrap, posterior, ...).
+    pub support: Option<f64>,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+impl Node {
+    pub fn new() -> Self {
+        Node::default()
+    }
+
+    pub fn with_label<S: Into<String>>(label: S) -> Self {
+        Node {
+            label: Some(label.into()),
+            ..Node::default()
+        }
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// A phylogenetic tree stored as an arena of nodes.
+///
+/// Every tree has a root node, even unrooted ones: for those the root is
+/// simply where the Newick string happened to start, and `is_rooted` is false.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    rooted: bool,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tree {
+    /// A rooted tree consisting of a single, unlabelled root node.
+    pub fn new() -> Self {
+        Tree {
+            nodes: vec![Node::new()],
+            rooted: true,
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        0
+    }
+
+    pub fn is_rooted(&self) -> bool {
+        self.rooted
+    }
+
+    pub fn set_rooted(&mut self, rooted: bool) {
+        self.rooted = rooted;
+    }
+
+    /// Number of nodes in the tree.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// A tree always has a root, so it is never empty.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn node_mut(&mut self, id: NodeId) -> &mut Node {
+        &mut self.nodes[id]
+    }
+
+    /// Attach `node` as the last child of `parent`, returning its id.
+    pub fn add_child(&mut self, parent: NodeId, mut node: Node) -> NodeId {
+        let id = self.nodes.len();
+        node.parent = Some(parent);
+        node.children.clear();
+        self.nodes.push(node);
+        self.nodes[parent].children.push(id);
+        id
+    }
+
+    /// The first node carrying `label`.
+    pub fn find(&self, label: &str) -> Option<NodeId> {
+        self.nodes.iter().position(|node| node.label.as_deref() == Some(label))
+    }
+
+    pub fn leaves(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.preorder().filter(move |&id| self.nodes[id].is_leaf())
+    }
+
+    /// Parents before children, children in order.
+    pub fn preorder(&self) -> Preorder<'_> {
+        Preorder { tree: self, stack: vec![self.root()] }
+    }
+
+    /// Children before parents, children in order.
+    pub fn postorder(&self) -> Postorder<'_> {
+        Postorder { tree: self, stack: vec![(self.root(), false)] }
+    }
+
+    /// Breadth first, starting at the root.
+    pub fn level_order(&self) -> LevelOrder<'_> {
+        LevelOrder { tree: self, queue: vec![self.root()].into_iter().collect() }
+    }
+
+    /// Number of edges between `id` and the root.
+    pub fn depth(&self, id: NodeId) -> usize {
+        self.ancestors(id).count()
+    }
+
+    /// Ancestors of `id`, nearest first, not including `id` itself.
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut current = self.nodes[id].parent;
+        ::std::iter::from_fn(move || {
+            let next = current?;
+            current = self.nodes[next].parent;
+            Some(next)
+        })
+    }
+
+    /// Lowest common ancestor of two nodes.
+    pub fn lca(&self, a: NodeId, b: NodeId) -> NodeId {
+        let (mut a, mut b) = (a, b);
+        let (mut depth_a, mut depth_b) = (self.depth(a), self.depth(b));
+        while depth_a > depth_b {
+            a = self.nodes[a].parent.expect("non-root node has a parent");
+            depth_a -= 1;
+        }
+        while depth_b > depth_a {
+            b = self.nodes[b].parent.expect("non-root node has a parent");
+            depth_b -= 1;
+        }
+        while a != b {
+            a = self.nodes[a].parent.expect("nodes in one tree share the root");
+            b = self.nodes[b].parent.expect("nodes in one tree share the root");
+        }
+        a
+    }
+
+    /// Number of edges on the path between two nodes.
+    pub fn path_length(&self, a: NodeId, b: NodeId) -> usize {
+        let lca = self.lca(a, b);
+        self.depth(a) + self.depth(b) - 2 * self.depth(lca)
+    }
+
+    /// Sum of branch lengths on the path between two nodes, treating missing lengths as zero.
+    pub fn distance(&self, a: NodeId, b: NodeId) -> f64 {
+        let lca = self.lca(a, b);
+        let length_to = |from: NodeId| {
+            let mut total = 0.0;
+            let mut current = from;
+            while current != lca {
+                total += self.nodes[current].branch_length.unwrap_or(0.0);
+                current = self.nodes[current].parent.expect("lca is an ancestor");
+            }
+            total
+        };
+        length_to(a) + length_to(b)
+    }
+
+    pub fn from_newick(input: &str) -> Result<Self, NewickError> {
+        newick::parse(input)
+    }
+
+    pub fn to_newick(&self) -> String {
+        newick::write(self)
+    }
+}
+
+impl FromStr for Tree {
+    type Err = NewickError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tree::from_newick(s)
+    }
+}
+
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_newick())
+    }
+}
+
+pub struct Preorder<'a> {
+    tree: &'a Tree,
+    stack: Vec<NodeId>,
+}
+
+impl<'a> Iterator for Preorder<'a> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.stack.pop()?;
+        self.stack.extend(self.tree.nodes[id].children.iter().rev());
+        Some(id)
+    }
+}
+
+pub struct Postorder<'a> {
+    tree: &'a Tree,
+    stack: Vec<(NodeId, bool)>,
+}
+
+impl<'a> Iterator for Postorder<'a> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (id, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some(id);
+            }
+            self.stack.push((id, true));
+            self.stack.extend(self.tree.nodes[id].children.iter().rev().map(|&child| (child, false)));
+        }
+    }
+}
+
+pub struct LevelOrder<'a> {
+    tree: &'a Tree,
+    queue: VecDeque<NodeId>,
+}
+
+impl<'a> Iterator for LevelOrder<'a> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.queue.pop_front()?;
+        self.queue.extend(self.tree.nodes[id].children.iter());
+        Some(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels<I: Iterator<Item = NodeId>>(tree: &Tree, ids: I) -> Vec<String> {
+        ids.map(|id| tree.node(id).label.clone().unwrap_or_default()).collect()
+    }
+
+    #[test]
+    fn traversal_orders() {
+        let tree: Tree = "((a,b)c,(d,e)f)g;".parse().unwrap();
+        assert_eq!(labels(&tree, tree.preorder()), vec!["g", "c", "a", "b", "f", "d", "e"]);
+        assert_eq!(labels(&tree, tree.postorder()), vec!["a", "b", "c", "d", "e", "f", "g"]);
+        assert_eq!(labels(&tree, tree.level_order()), vec!["g", "c", "f", "a", "b", "d", "e"]);
+        assert_eq!(labels(&tree, tree.leaves()), vec!["a", "b", "d", "e"]);
+    }
+
+    #[test]
+    fn lowest_common_ancestor() {
+        let tree: Tree = "((a:1,b:2)c:0.5,(d,e)f)g;".parse().unwrap();
+        let find = |label| tree.find(label).unwrap();
+        assert_eq!(tree.lca(find("a"), find("b")), find("c"));
+        assert_eq!(tree.lca(find("a"), find("e")), find("g"));
+        assert_eq!(tree.lca(find("c"), find("a")), find("c"));
+        assert_eq!(tree.path_length(find("a"), find("d")), 4);
+        assert_eq!(tree.distance(find("a"), find("b")), 3.0);
+        assert_eq!(tree.distance(find("a"), find("e")), 1.5);
+    }
+}
@@ -0,0 +1,341 @@
+use std::error;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+use tree::{Node, NodeId, Tree};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewickError {
+    /// Byte offset into the input where parsing failed.
+    pub position: usize,
+    pub message: String,
+}
+
+impl NewickError {
+    fn new<S: Into<String>>(position: usize, message: S) -> Self {
+        NewickError { position, message: message.into() }
+    }
+}
+
+impl fmt::Display for NewickError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid Newick at byte {}: {}", self.position, self.message)
+    }
+}
+
+impl error::Error for NewickError {}
+
+/// Parse exactly one tree, ignoring surrounding whitespace.
+pub fn parse(input: &str) -> Result<Tree, NewickError> {
+    let mut parser = Parser::new(input);
+    let tree = parser.next_tree()?
+        .ok_or_else(|| NewickError::new(input.len(), "Expected a tree"))?;
+    parser.skip_blanks()?;
+    match parser.chars.peek() {
+        None => Ok(tree),
+        Some(&(pos, _)) => Err(NewickError::new(pos, "Unexpected text after ';'")),
+    }
+}
+
+/// Parse every tree in a string of `;`-terminated trees.
+pub fn parse_all(input: &str) -> Result<Vec<Tree>, NewickError> {
+    let mut parser = Parser::new(input);
+    let mut trees = Vec::new();
+    while let Some(tree) = parser.next_tree()? {
+        trees.push(tree);
+    }
+    Ok(trees)
+}
+
+/// Characters that end an unquoted label or branch length.
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || "()[]':;,".contains(c)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser { input, chars: input.char_indices().peekable() }
+    }
+
+    fn position(&mut self) -> usize {
+        let len = self.input.len();
+        self.chars.peek().map_or(len, |&(pos, _)| pos)
+    }
+
+    /// Skip whitespace and comments, returning the rooting hint from any `[&R]` or `[&U]` comment.
+    fn skip_blanks(&mut self) -> Result<Option<bool>, NewickError> {
+        let mut rooted = None;
+        while let Some(&(_, c)) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.chars.next();
+            } else if c == '[' {
+                let comment = self.comment()?;
+                match comment.trim() {
+                    "&R" | "&r" => rooted = Some(true),
+                    "&U" | "&u" => rooted = Some(false),
+                    _ => {}
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(rooted)
+    }
+
+    fn comment(&mut self) -> Result<&'a str, NewickError> {
+        let (start, _) = self.chars.next().expect("caller saw '['");
+        for (pos, c) in self.chars.by_ref() {
+            if c == ']' {
+                return Ok(&self.input[start + 1..pos]);
+            }
+        }
+        Err(NewickError::new(start, "Unterminated comment"))
+    }
+
+    fn next_tree(&mut self) -> Result<Option<Tree>, NewickError> {
+        let hint = self.skip_blanks()?;
+        if self.chars.peek().is_none() {
+            return Ok(None);
+        }
+
+        let mut tree = Tree::new();
+        let mut current = tree.root();
+        // Whether `current` has already been given a label or length.
+        let mut labelled = false;
+        let mut has_length = false;
+
+        loop {
+            self.skip_blanks()?;
+            let pos = self.position();
+            let c = match self.chars.peek() {
+                Some(&(_, c)) => c,
+                None => return Err(NewickError::new(pos, "Missing ';' at end of tree")),
+            };
+            match c {
+                '(' => {
+                    self.chars.next();
+                    if labelled || has_length || !tree.node(current).is_leaf() {
+                        return Err(NewickError::new(pos, "Unexpected '('"));
+                    }
+                    current = tree.add_child(current, Node::new());
+                }
+                ',' => {
+                    self.chars.next();
+                    let parent = tree.node(current).parent()
+                        .ok_or_else(|| NewickError::new(pos, "Unexpected ',' outside parentheses"))?;
+                    current = tree.add_child(parent, Node::new());
+                    labelled = false;
+                    has_length = false;
+                }
+                ')' => {
+                    self.chars.next();
+                    current = tree.node(current).parent()
+                        .ok_or_else(|| NewickError::new(pos, "Unbalanced ')'"))?;
+                    labelled = false;
+                    has_length = false;
+                }
+                ':' => {
+                    self.chars.next();
+                    if has_length {
+                        return Err(NewickError::new(pos, "Node has two branch lengths"));
+                    }
+                    self.skip_blanks()?;
+                    let start = self.position();
+                    let text = self.unquoted();
+                    let length = text.parse::<f64>()
+                        .map_err(|_| NewickError::new(start, format!("Invalid branch length '{}'", text)))?;
+                    tree.node_mut(current).branch_length = Some(length);
+                    has_length = true;
+                }
+                ';' => {
+                    self.chars.next();
+                    if current != tree.root() {
+                        return Err(NewickError::new(pos, "Unbalanced '(' before ';'"));
+                    }
+                    break;
+                }
+                _ => {
+                    if labelled || has_length {
+                        return Err(NewickError::new(pos, "Unexpected label"));
+                    }
+                    let (text, quoted) = match self.unquoted() {
+                        "" if c == '\'' => (self.quoted()?, true),
+                        "" => return Err(NewickError::new(pos, format!("Unexpected '{}'", c))),
+                        text => (text.to_owned(), false),
+                    };
+                    let node = tree.node_mut(current);
+                    // Numbers on internal nodes are support values by convention.
+                    match text.parse::<f64>() {
+                        Ok(support) if !quoted && !node.is_leaf() => node.support = Some(support),
+                        _ => node.label = Some(text),
+                    }
+                    labelled = true;
+                }
+            }
+        }
+
+        let root_children = tree.node(tree.root()).children().len();
+        tree.set_rooted(hint.unwrap_or(root_children < 3));
+        Ok(Some(tree))
+    }
+
+    fn unquoted(&mut self) -> &'a str {
+        let start = self.position();
+        while let Some(&(_, c)) = self.chars.peek() {
+            if is_delimiter(c) {
+                break;
+            }
+            self.chars.next();
+        }
+        let end = self.position();
+        &self.input[start..end]
+    }
+
+    /// A single-quoted label, where `''` stands for a literal quote.
+    fn quoted(&mut self) -> Result<String, NewickError> {
+        let (start, _) = self.chars.next().expect("caller saw a quote");
+        let mut label = String::new();
+        while let Some((_, c)) = self.chars.next() {
+            if c == '\'' {
+                match self.chars.peek() {
+                    Some(&(_, '\'')) => {
+                        self.chars.next();
+                    }
+                    _ => return Ok(label),
+                }
+            }
+            label.push(c);
+        }
+        Err(NewickError::new(start, "Unterminated quoted label"))
+    }
+}
+
+pub fn write(tree: &Tree) -> String {
+    let mut out = String::new();
+    if tree.is_rooted() != (tree.node(tree.root()).children().len() < 3) {
+        out.push_str(if tree.is_rooted() { "[&R] " } else { "[&U] " });
+    }
+    write_node(tree, tree.root(), &mut out);
+    out.push(';');
+    out
+}
+
+/// A pending piece of output, so deep trees are written without recursion.
+enum Step {
+    Node(NodeId),
+    Comma,
+    /// Close a node's children, then write the node itself.
+    Close(NodeId),
+}
+
+fn write_node(tree: &Tree, root: NodeId, out: &mut String) {
+    let mut stack = vec![Step::Node(root)];
+    while let Some(step) = stack.pop() {
+        match step {
+            Step::Node(id) if tree.node(id).is_leaf() => write_annotations(tree.node(id), out),
+            Step::Node(id) => {
+                out.push('(');
+                stack.push(Step::Close(id));
+                for (i, &child) in tree.node(id).children().iter().enumerate().rev() {
+                    stack.push(Step::Node(child));
+                    if i > 0 {
+                        stack.push(Step::Comma);
+                    }
+                }
+            }
+            Step::Comma => out.push(','),
+            Step::Close(id) => {
+                out.push(')');
+                write_annotations(tree.node(id), out);
+            }
+        }
+    }
+}
+
+/// A node's label or support, then its branch length. Newick has room for
+/// only one name, so a label takes precedence and support is written only
+/// for unlabelled internal nodes.
+fn write_annotations(node: &Node, out: &mut String) {
+    match (&node.label, node.support) {
+        (Some(label), _) => write_label(label, out),
+        (None, Some(support)) if !node.is_leaf() => out.push_str(&support.to_string()),
+        _ => {}
+    }
+    if let Some(length) = node.branch_length {
+        out.push(':');
+        out.push_str(&length.to_string());
+    }
+}
+
+fn write_label(label: &str, out: &mut String) {
+    if !label.is_empty() && !label.contains(is_delimiter) {
+        out.push_str(label);
+    } else {
+        out.push('\'');
+        out.push_str(&label.replace('\'', "''"));
+        out.push('\'');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_labels_and_comments() {
+        let tree = parse("[&U] ('Homo sapiens':0.1[note],'O''Brien',(x, y)95:2)root;").unwrap();
+        assert!(!tree.is_rooted());
+        let human = tree.find("Homo sapiens").unwrap();
+        assert_eq!(tree.node(human).branch_length, Some(0.1));
+        assert!(tree.find("O'Brien").is_some());
+        let clade = tree.lca(tree.find("x").unwrap(), tree.find("y").unwrap());
+        assert_eq!(tree.node(clade).support, Some(95.0));
+        assert_eq!(tree.node(clade).label, None);
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in &["(a,b,(c,d));", "((a:1,b:0.5)90:3,'c d')e;", "(,,(,));", "[&U] (a,b);"] {
+            let tree = parse(input).unwrap();
+            assert_eq!(&tree.to_newick(), input);
+            assert_eq!(parse(&tree.to_newick()).unwrap(), tree);
+        }
+    }
+
+    #[test]
+    fn labels_take_precedence_over_support() {
+        let mut tree = parse("((a,b)90,c);").unwrap();
+        let clade = tree.lca(tree.find("a").unwrap(), tree.find("b").unwrap());
+        tree.node_mut(clade).label = Some("ab".to_owned());
+        assert_eq!(tree.to_newick(), "((a,b)ab,c);");
+    }
+
+    #[test]
+    fn deep_trees() {
+        let depth = 100_000;
+        let input = format!("{}a{};", "(".repeat(depth), ",b)".repeat(depth));
+        assert_eq!(parse(&input).unwrap().to_newick(), input);
+    }
+
+    #[test]
+    fn multiple_trees() {
+        let trees = parse_all("(a,b);\n\n(c,(d,e));\n").unwrap();
+        assert_eq!(trees.len(), 2);
+        assert_eq!(trees[1].leaves().count(), 3);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("(a,b").unwrap_err().position, 4);
+        assert!(parse("(a,b));").is_err());
+        assert!(parse("(a:x,b);").is_err());
+        assert!(parse("a,b;").is_err());
+        assert!(parse("(a,b); (c,d);").is_err());
+        assert!(parse("(a]b);").is_err());
+    }
+}
@@ -0,0 +1,50 @@
+extern crate rustalind;
+
+use std::process::Command;
+use rustalind::check;
+
+#[test]
+fn sample_answers_pass() {
+    let cases = check::discover("test-data").unwrap();
+    assert!(cases.iter().any(|case| case.problem == "iprb"));
+    for case in &cases {
+        let outcome = check::run(case);
+        assert!(outcome.passed(), "{}/{}: {}", case.problem, case.name, outcome);
+    }
+}
+
+#[test]
+fn check_subcommand_reports_a_table() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rustalind"))
+        .arg("check")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let table = String::from_utf8(output.stdout).unwrap();
+    assert!(table.starts_with("problem"));
+    let gc_row = table.lines().find(|line| line.split_whitespace().take(2).eq(vec!["gc", "gc"])).unwrap();
+    assert!(gc_row.ends_with("PASS"));
+}
+
+#[test]
+fn solve_writes_answer_files() {
+    let dir = std::env::temp_dir().join(format!("rustalind-solve-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::copy("test-data/gc/rosalind_gc.txt", dir.join("rosalind_gc.txt")).unwrap();
+    std::fs::write(dir.join("rosalind_fib.txt"), "5 3\n").unwrap();
+    std::fs::write(dir.join("rosalind_iprb.txt"), "2 2 2\n").unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_rustalind"))
+        .arg("solve")
+        .arg(&dir)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success());
+
+    let answer = |id: &str| std::fs::read_to_string(dir.join(format!("{}.out", id))).unwrap();
+    assert!(check::outputs_match(&answer("gc"), &std::fs::read_to_string("test-data/gc/rosalind_gc.expected").unwrap()));
+    assert_eq!(answer("fib"), "19\n");
+    assert!(check::outputs_match(&answer("iprb"), "0.78333"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
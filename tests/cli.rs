@@ -0,0 +1,44 @@
+use std::process::Command;
+
+fn rustalind(args: &[&str]) -> Option<i32> {
+    Command::new(env!("CARGO_BIN_EXE_rustalind"))
+        .args(args)
+        .output()
+        .unwrap()
+        .status
+        .code()
+}
+
+#[test]
+fn exit_codes() {
+    assert_eq!(rustalind(&["fib", "-g", "5", "-f", "3"]), Some(0));
+    assert_eq!(rustalind(&["fib", "-g", "five", "-f", "3"]), Some(2));
+    assert_eq!(rustalind(&["dna", "test-data"]), Some(3));
+    assert_eq!(rustalind(&["gc", "--strict", "test-data/dna/dna.txt"]), Some(4));
+    assert_eq!(rustalind(&["iprb", "-k", "0", "-m", "0", "-n", "0"]), Some(5));
+    assert_eq!(rustalind(&["fib", "-g", "1000", "-f", "5"]), Some(0));
+    assert_eq!(rustalind(&["glob", "--gap", "4294967295", "test-data/glob/glob.fasta"]), Some(2));
+}
+
+fn pipe(args: &[&str], input: &str) -> String {
+    use std::io::Write;
+    use std::process::Stdio;
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rustalind"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn fasta_pipeline() {
+    // cat x.fa | rustalind revc | rustalind prot
+    let reverse = pipe(&["revc"], ">x\nTTAGGC\nCAT\n");
+    assert_eq!(reverse.trim(), "ATGGCCTAA");
+    assert_eq!(pipe(&["prot"], &reverse).trim(), "MA");
+}